@@ -0,0 +1,63 @@
+//! Times the `f32` hot paths over a million elements.
+//! Run with and without `--features simd` to compare the backends.
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use ewq::mat::Mat4f;
+use ewq::vec::{Vec3f, Vec4f};
+use ewq::Quatf;
+
+const N: usize = 1 << 20;
+const RUNS: usize = 200;
+
+/// Prints the best time of `RUNS` runs of `f`.
+fn bench<R>(name: &str, f: impl Fn() -> R) {
+    let best = (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or(Duration::ZERO);
+    println!("{:<20} {:?}", name, best);
+}
+
+fn main() {
+    let v3: Vec<Vec3f> = (0..N)
+        .map(|i| Vec3f::new(i as f32, 1. + i as f32 * 0.5, 2.))
+        .collect();
+    let v4: Vec<Vec4f> = (0..N).map(|i| Vec4f::new(i as f32, 1., 2., 0.5)).collect();
+    let q = Quatf::new_axis_rotation(Vec3f::new(1., 2., 3.), 0.7);
+    let m = Mat4f::from_trs(Vec3f::new(1., 2., 3.), q, Vec3f::new(1., 2., 3.));
+    let ms: Vec<Mat4f> = (0..N / 16).map(|i| m * (i as f32)).collect();
+    let a = black_box(Vec3f::new(0.3, 0.2, 0.1));
+
+    bench("Vec3::dot", || v3.iter().map(|v| v.dot(a)).sum::<f32>());
+    bench("Vec3::normalized", || {
+        v3.iter()
+            .map(|v| v.normalized())
+            .fold(Vec3f::ZERO, |s, v| s + v)
+    });
+    bench("Vec4::dot", || v4.iter().map(|v| v.dot(*v)).sum::<f32>());
+    bench("Vec4::normalized", || {
+        v4.iter()
+            .map(|v| v.normalized())
+            .fold(Vec4f::ZERO, |s, v| s + v)
+    });
+    bench("Quat::rotate", || {
+        v3.iter()
+            .map(|v| q.rotate(*v))
+            .fold(Vec3f::ZERO, |s, v| s + v)
+    });
+    bench("Quat::rotate_unit", || {
+        v3.iter()
+            .map(|v| q.rotate_unit(*v))
+            .fold(Vec3f::ZERO, |s, v| s + v)
+    });
+    bench("Mat4 * Vec4", || {
+        v4.iter().map(|v| m * *v).fold(Vec4f::ZERO, |s, v| s + v)
+    });
+    bench("Mat4 * Mat4", || {
+        ms.iter().fold(Mat4f::identity(), |s, x| s * *x)
+    });
+}
@@ -1,4 +1,4 @@
-use num_traits::Float;
+use crate::{Real, Scalar};
 use crate::vec::Vec3;
 
 pub type Aabb3f = Aabb3<f32>;
@@ -7,7 +7,7 @@ pub type Aabb3d = Aabb3<f64>;
 #[derive(Debug, PartialEq, Default, Clone, Copy)]
 pub struct Aabb3<F>
 where
-    F: Float
+    F: Scalar
 {
     pub min: Vec3<F>,
     pub max: Vec3<F>
@@ -15,11 +15,11 @@ where
 
 impl<F> Aabb3<F>
 where
-    F: Float
+    F: Scalar
 {
     /// Creates new [`Aabb3`] with `min` and `max` vectors.
     #[inline]
-    pub fn new(min: Vec3<F>, max: Vec3<F>) -> Self {
+    pub const fn new(min: Vec3<F>, max: Vec3<F>) -> Self {
         Self { min, max }
     }
 
@@ -95,4 +95,23 @@ where
     pub fn volume(&self) -> F {
         (self.max - self.min).cuboid_volume()
     }
+}
+
+impl<F> Aabb3<F>
+where
+    F: Real
+{
+    /// Converts the [`Aabb3`] to the other floating point precision.
+    /// ```
+    /// # use ewq::{vecf, aabb::{Aabb3, Aabb3d}};
+    /// let aabb = Aabb3::new(vecf!(1, 1, 1), vecf!(2.5, 2.5, 2.5));
+    /// assert_eq!(aabb.cast::<f64>(), Aabb3d::new(vecf!(1, 1, 1).cast(), vecf!(2.5, 2.5, 2.5).cast()));
+    /// ```
+    #[inline]
+    pub fn cast<T>(&self) -> Aabb3<T>
+    where
+        T: Real
+    {
+        Aabb3::new(self.min.cast(), self.max.cast())
+    }
 }
\ No newline at end of file
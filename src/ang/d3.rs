@@ -1,5 +1,5 @@
 use crate::{vec::Vec3, Quat};
-use num_traits::Float;
+use crate::{Real, Scalar};
 
 pub type Ang3f = Ang3<f32>;
 pub type Ang3d = Ang3<f32>;
@@ -12,7 +12,7 @@ pub type Ang3d = Ang3<f32>;
 #[repr(C)]
 pub struct Ang3<F>
 where
-    F: Float,
+    F: Scalar,
 {
     /// Rotation around +Z.
     pub yaw: F,
@@ -24,14 +24,19 @@ where
 
 impl<F> Ang3<F>
 where
-    F: Float,
+    F: Scalar,
 {
     /// Creates new rotation.
     #[inline]
-    pub fn new(yaw: F, pitch: F, roll: F) -> Self {
+    pub const fn new(yaw: F, pitch: F, roll: F) -> Self {
         Self { yaw, pitch, roll }
     }
+}
 
+impl<F> Ang3<F>
+where
+    F: Real,
+{
     /// Creates a quaternion representing this rotation in order Yaw, Pitch, Roll.
     #[inline]
     pub fn into_rotation(self) -> Quat<F> {
@@ -43,7 +48,7 @@ where
 
 impl<F> From<Vec3<F>> for Ang3<F>
 where
-    F: Float,
+    F: Scalar,
 {
     fn from(v: Vec3<F>) -> Self {
         Self {
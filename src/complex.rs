@@ -1,5 +1,5 @@
 use crate::vec::Vec2;
-use num_traits::Float;
+use crate::{scalar::relative_eq, Real, Scalar};
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 /// Complex number with real and imaginary parts.
@@ -7,7 +7,7 @@ use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssi
 #[repr(C)]
 pub struct Complex<F>
 where
-    F: Float,
+    F: Scalar,
 {
     pub real: F,
     pub imag: F,
@@ -15,11 +15,11 @@ where
 
 impl<F> Complex<F>
 where
-    F: Float,
+    F: Scalar,
 {
     /// Creates new complex number.
     #[inline]
-    pub fn new(real: F, imag: F) -> Self {
+    pub const fn new(real: F, imag: F) -> Self {
         Self { real, imag }
     }
 
@@ -40,7 +40,12 @@ where
             imag: -self.imag,
         }
     }
+}
 
+impl<F> Complex<F>
+where
+    F: Real,
+{
     /// Raises complex number to the second power.
     pub fn pow2(&self) -> Self {
         Self {
@@ -48,11 +53,238 @@ where
             imag: F::from(2).unwrap() * self.real * self.imag,
         }
     }
+
+    /// Creates new complex number from polar coordinates.
+    #[inline]
+    pub fn from_polar(r: F, theta: F) -> Self {
+        let (sin, cos) = theta.sin_cos();
+        Self {
+            real: r * cos,
+            imag: r * sin,
+        }
+    }
+
+    /// Computes the absolute value (modulus) of the complex number.
+    #[inline]
+    pub fn norm(&self) -> F {
+        self.real.hypot(self.imag)
+    }
+
+    /// Computes the argument (phase) of the complex number in range `(-PI, PI]`.
+    #[inline]
+    pub fn arg(&self) -> F {
+        self.imag.atan2(self.real)
+    }
+
+    /// Computes `e` raised to the power of the complex number.
+    /// ```
+    /// # use ewq::Complex;
+    /// let c = Complex::new(0., std::f64::consts::PI).exp();
+    /// assert!(c.abs_diff_eq(&Complex::new(-1., 0.), 1e-12));
+    /// ```
+    #[inline]
+    pub fn exp(&self) -> Self {
+        Self::from_polar(self.real.exp(), self.imag)
+    }
+
+    /// Computes the principal value of the natural logarithm.
+    /// ```
+    /// # use ewq::Complex;
+    /// let c = Complex::new(1., 2.);
+    /// assert!(c.ln().exp().abs_diff_eq(&c, 1e-12));
+    /// ```
+    #[inline]
+    pub fn ln(&self) -> Self {
+        Self {
+            real: self.norm().ln(),
+            imag: self.arg(),
+        }
+    }
+
+    /// Computes the principal square root, with nonnegative real part.
+    /// ```
+    /// # use ewq::Complex;
+    /// assert_eq!(Complex::new(-4., 0.).sqrt(), Complex::new(0., 2.));
+    /// assert_eq!(Complex::new(3., 4.).sqrt(), Complex::new(2., 1.));
+    /// ```
+    #[inline]
+    pub fn sqrt(&self) -> Self {
+        if self.real == F::zero() && self.imag == F::zero() {
+            return *self;
+        }
+        let two = F::one() + F::one();
+        let t = ((self.real.abs() + self.norm()) / two).sqrt();
+        if self.real >= F::zero() {
+            Self {
+                real: t,
+                imag: self.imag / (two * t),
+            }
+        } else {
+            Self {
+                real: self.imag.abs() / (two * t),
+                imag: t.copysign(self.imag),
+            }
+        }
+    }
+
+    /// Raises the complex number to the real power using the principal branch.
+    /// Any number raised to zero yields one. Zero raised to a positive power yields zero
+    /// and raised to a negative power yields infinity.
+    /// ```
+    /// # use ewq::Complex;
+    /// let c = Complex::new(1., 1.);
+    /// assert!(c.powf(2.).abs_diff_eq(&Complex::new(0., 2.), 1e-12));
+    /// let zero = Complex::new(0., 0.);
+    /// assert_eq!(zero.powf(0.), Complex::new(1., 0.));
+    /// assert_eq!(zero.powf(2.), zero);
+    /// assert_eq!(zero.powf(-1.), Complex::new(f64::INFINITY, 0.));
+    /// ```
+    #[inline]
+    pub fn powf(&self, exp: F) -> Self {
+        if exp == F::zero() {
+            return Self::new(F::one(), F::zero());
+        }
+        if self.real == F::zero() && self.imag == F::zero() {
+            return Self::new(F::zero().powf(exp), F::zero());
+        }
+        Self::from_polar(self.norm().powf(exp), self.arg() * exp)
+    }
+
+    /// Raises the complex number to the complex power using the principal branch.
+    /// Any number raised to zero yields one. Zero raised to a power with positive real part
+    /// yields zero, real powers of zero behave as in [`Complex::powf`] and the rest yield NaN.
+    /// ```
+    /// # use ewq::Complex;
+    /// // i^i = e^(-PI/2)
+    /// let i = Complex::new(0., 1.);
+    /// let c = i.powc(i);
+    /// assert!(c.abs_diff_eq(&Complex::new((-std::f64::consts::FRAC_PI_2).exp(), 0.), 1e-12));
+    /// let zero = Complex::new(0., 0.);
+    /// assert_eq!(zero.powc(zero), Complex::new(1., 0.));
+    /// assert_eq!(zero.powc(Complex::new(1., 1.)), zero);
+    /// ```
+    #[inline]
+    pub fn powc(&self, exp: Self) -> Self {
+        if self.real == F::zero() && self.imag == F::zero() {
+            if exp.imag == F::zero() {
+                return self.powf(exp.real);
+            }
+            if exp.real > F::zero() {
+                return Self::new(F::zero(), F::zero());
+            }
+        }
+        if exp.real == F::zero() && exp.imag == F::zero() {
+            return Self::new(F::one(), F::zero());
+        }
+        (exp * self.ln()).exp()
+    }
+
+    /// Computes the sine of the complex number.
+    /// ```
+    /// # use ewq::Complex;
+    /// let z = Complex::new(0.5, -1.5);
+    /// let (s, c) = (z.sin(), z.cos());
+    /// assert!((s * s + c * c).abs_diff_eq(&Complex::new(1., 0.), 1e-12));
+    /// assert!((s / c).abs_diff_eq(&z.tan(), 1e-12));
+    /// ```
+    #[inline]
+    pub fn sin(&self) -> Self {
+        Self {
+            real: self.real.sin() * self.imag.cosh(),
+            imag: self.real.cos() * self.imag.sinh(),
+        }
+    }
+
+    /// Computes the cosine of the complex number.
+    #[inline]
+    pub fn cos(&self) -> Self {
+        Self {
+            real: self.real.cos() * self.imag.cosh(),
+            imag: -self.real.sin() * self.imag.sinh(),
+        }
+    }
+
+    /// Computes the tangent of the complex number.
+    /// ```
+    /// # use ewq::Complex;
+    /// let t = Complex::new(0.5, 400.).tan();
+    /// assert!(t.abs_diff_eq(&Complex::new(0., 1.), 1e-12));
+    /// ```
+    #[inline]
+    pub fn tan(&self) -> Self {
+        // tan(z) = -i * tanh(i * z)
+        let t = Self::new(-self.imag, self.real).tanh();
+        Self::new(t.imag, -t.real)
+    }
+
+    /// Computes the hyperbolic sine of the complex number.
+    /// ```
+    /// # use ewq::Complex;
+    /// let z = Complex::new(0.5, -1.5);
+    /// let (s, c) = (z.sinh(), z.cosh());
+    /// assert!((c * c - s * s).abs_diff_eq(&Complex::new(1., 0.), 1e-12));
+    /// assert!((s / c).abs_diff_eq(&z.tanh(), 1e-12));
+    /// ```
+    #[inline]
+    pub fn sinh(&self) -> Self {
+        Self {
+            real: self.real.sinh() * self.imag.cos(),
+            imag: self.real.cosh() * self.imag.sin(),
+        }
+    }
+
+    /// Computes the hyperbolic cosine of the complex number.
+    #[inline]
+    pub fn cosh(&self) -> Self {
+        Self {
+            real: self.real.cosh() * self.imag.cos(),
+            imag: self.real.sinh() * self.imag.sin(),
+        }
+    }
+
+    /// Computes the hyperbolic tangent of the complex number.
+    /// ```
+    /// # use ewq::Complex;
+    /// let t = Complex::new(-400., 0.5).tanh();
+    /// assert!(t.abs_diff_eq(&Complex::new(-1., 0.), 1e-12));
+    /// ```
+    #[inline]
+    pub fn tanh(&self) -> Self {
+        // Past this point `cosh` and `sinh` may overflow while `tanh` of the real part
+        // has already rounded to one, so use the asymptotic form instead.
+        if self.real.abs() > -F::epsilon().ln() {
+            let two = F::one() + F::one();
+            return Self {
+                real: F::one().copysign(self.real),
+                imag: two * (two * self.imag).sin() * (-two * self.real.abs()).exp(),
+            };
+        }
+        let (a, b) = (self.real + self.real, self.imag + self.imag);
+        let d = a.cosh() + b.cos();
+        Self {
+            real: a.sinh() / d,
+            imag: b.sin() / d,
+        }
+    }
+
+    /// Checks if real and imaginary parts of two numbers differ by no more than `epsilon`.
+    #[inline]
+    pub fn abs_diff_eq(&self, other: &Self, epsilon: F) -> bool {
+        (self.real - other.real).abs() <= epsilon && (self.imag - other.imag).abs() <= epsilon
+    }
+
+    /// Checks if real and imaginary parts of two numbers are equal within `epsilon`
+    /// or within `max_relative` of the larger magnitude.
+    #[inline]
+    pub fn relative_eq(&self, other: &Self, epsilon: F, max_relative: F) -> bool {
+        relative_eq(self.real, other.real, epsilon, max_relative)
+            && relative_eq(self.imag, other.imag, epsilon, max_relative)
+    }
 }
 
 impl<F> Add for Complex<F>
 where
-    F: Float,
+    F: Scalar,
 {
     type Output = Self;
 
@@ -67,7 +299,7 @@ where
 
 impl<F> AddAssign for Complex<F>
 where
-    F: Float,
+    F: Scalar,
 {
     #[inline]
     fn add_assign(&mut self, rhs: Self) {
@@ -78,7 +310,7 @@ where
 
 impl<F> Sub for Complex<F>
 where
-    F: Float,
+    F: Scalar,
 {
     type Output = Self;
 
@@ -93,7 +325,7 @@ where
 
 impl<F> SubAssign for Complex<F>
 where
-    F: Float,
+    F: Scalar,
 {
     #[inline]
     fn sub_assign(&mut self, rhs: Self) {
@@ -104,7 +336,7 @@ where
 
 impl<F> Mul<F> for Complex<F>
 where
-    F: Float,
+    F: Scalar,
 {
     type Output = Self;
 
@@ -119,7 +351,7 @@ where
 
 impl<F> Mul for Complex<F>
 where
-    F: Float,
+    F: Scalar,
 {
     type Output = Self;
 
@@ -134,18 +366,25 @@ where
 
 impl<F> MulAssign for Complex<F>
 where
-    F: Float,
+    F: Scalar,
 {
+    /// Multiplies the complex number by the other complex number in place.
+    /// ```
+    /// # use ewq::Complex;
+    /// let (a, b) = (Complex::new(1., 2.), Complex::new(3., -1.));
+    /// let mut c = a;
+    /// c *= b;
+    /// assert_eq!(c, a * b);
+    /// ```
     #[inline]
     fn mul_assign(&mut self, rhs: Self) {
-        self.real = self.real * rhs.real - self.imag * rhs.imag;
-        self.imag = self.real * rhs.imag + self.imag * rhs.real;
+        *self = *self * rhs;
     }
 }
 
 impl<F> MulAssign<F> for Complex<F>
 where
-    F: Float,
+    F: Scalar,
 {
     #[inline]
     fn mul_assign(&mut self, rhs: F) {
@@ -156,7 +395,7 @@ where
 
 impl<F> Div<F> for Complex<F>
 where
-    F: Float,
+    F: Scalar,
 {
     type Output = Self;
 
@@ -169,9 +408,55 @@ where
     }
 }
 
+impl<F> Div for Complex<F>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    /// Divides the complex number by the other complex number.
+    /// Uses Smith's algorithm to avoid intermediate overflow.
+    /// ```
+    /// # use ewq::Complex;
+    /// let c = Complex::new(1., 2.) / Complex::new(3., 4.);
+    /// assert!(c.abs_diff_eq(&Complex::new(0.44, 0.08), 1e-12));
+    /// assert_eq!(Complex::new(1e300, 1e300) / Complex::new(1e300, 1e300), Complex::new(1., 0.));
+    /// ```
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        let abs = |x: F| if x < F::zero() { -x } else { x };
+        let (a, b, c, d) = (self.real, self.imag, rhs.real, rhs.imag);
+        if abs(c) >= abs(d) {
+            let r = d / c;
+            let den = c + d * r;
+            Self {
+                real: (a + b * r) / den,
+                imag: (b - a * r) / den,
+            }
+        } else {
+            let r = c / d;
+            let den = c * r + d;
+            Self {
+                real: (a * r + b) / den,
+                imag: (b * r - a) / den,
+            }
+        }
+    }
+}
+
+impl<F> DivAssign for Complex<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
 impl<F> DivAssign<F> for Complex<F>
 where
-    F: Float,
+    F: Scalar,
 {
     #[inline]
     fn div_assign(&mut self, rhs: F) {
@@ -182,7 +467,7 @@ where
 
 impl<F> Neg for Complex<F>
 where
-    F: Float,
+    F: Scalar,
 {
     type Output = Self;
 
@@ -197,7 +482,7 @@ where
 
 impl<F> From<Vec2<F>> for Complex<F>
 where
-    F: Float,
+    F: Scalar,
 {
     fn from(v: Vec2<F>) -> Self {
         Self {
@@ -206,3 +491,40 @@ where
         }
     }
 }
+
+macro_rules! complex_scalar_mul {
+    ($($f:ty),+) => {$(
+        impl Mul<Complex<$f>> for $f {
+            type Output = Complex<$f>;
+
+            /// Multiplies the complex number by the scalar.
+            /// ```
+            /// # use ewq::Complex;
+            #[doc = concat!("let c = Complex::<", stringify!($f), ">::new(1., 2.);")]
+            /// assert_eq!(2. * c, c * 2.);
+            /// ```
+            #[inline]
+            fn mul(self, rhs: Complex<$f>) -> Self::Output {
+                rhs * self
+            }
+        }
+    )+};
+}
+
+complex_scalar_mul!(f32, f64);
+
+/// Generates constants for the floating point complex number types.
+macro_rules! complex_consts {
+    ($($f:ty),+) => {$(
+        impl Complex<$f> {
+            /// Complex zero.
+            pub const ZERO: Self = Self::new(0., 0.);
+            /// Real unit.
+            pub const ONE: Self = Self::new(1., 0.);
+            /// Imaginary unit.
+            pub const I: Self = Self::new(0., 1.);
+        }
+    )+};
+}
+
+complex_consts!(f32, f64);
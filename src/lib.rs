@@ -9,6 +9,11 @@ pub mod vec;
 pub mod aabb;
 /// Matricies.
 pub mod mat;
+/// Planes.
+pub mod plane;
+
+mod scalar;
+pub use scalar::*;
 
 mod complex;
 pub use complex::*;
@@ -17,3 +22,6 @@ mod quat;
 pub use quat::*;
 
 pub mod macros;
+
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+mod simd;
@@ -22,4 +22,37 @@ macro_rules! vecd {
     ($x:expr, $y:expr, $z:expr, $w:expr) => {
         $crate::vec::Vec4d::new($x as f64, $y as f64, $z as f64, $w as f64)
     };
+}
+
+/// Creates `f32` quaternion either from `(axis, angle)` or from `(x, y, z, w)` components.
+/// ```
+/// # use ewq::{quatf, vecf, Quatf};
+/// assert_eq!(quatf!(0, 0, 0, 1), Quatf::identity());
+/// let q = quatf!(vecf!(0, 0, 1), std::f32::consts::FRAC_PI_2);
+/// assert_eq!(q, Quatf::new_axis_rotation(vecf!(0, 0, 1), std::f32::consts::FRAC_PI_2));
+/// ```
+#[macro_export]
+macro_rules! quatf {
+    ($axis:expr, $angle:expr) => {
+        $crate::Quatf::new_axis_rotation($axis, $angle as f32)
+    };
+    ($x:expr, $y:expr, $z:expr, $w:expr) => {
+        $crate::Quatf::from_parts($x as f32, $y as f32, $z as f32, $w as f32)
+    };
+}
+
+/// Creates `f64` quaternion either from `(axis, angle)` or from `(x, y, z, w)` components.
+/// ```
+/// # use ewq::{quatd, vecd, Quatd};
+/// assert_eq!(quatd!(0, 0, 0, 1), Quatd::identity());
+/// assert!((quatd!(vecd!(1, 0, 0), 1).rotate(vecd!(1, 0, 0)) - vecd!(1, 0, 0)).magnitude() < 1e-12);
+/// ```
+#[macro_export]
+macro_rules! quatd {
+    ($axis:expr, $angle:expr) => {
+        $crate::Quatd::new_axis_rotation($axis, $angle as f64)
+    };
+    ($x:expr, $y:expr, $z:expr, $w:expr) => {
+        $crate::Quatd::from_parts($x as f64, $y as f64, $z as f64, $w as f64)
+    };
 }
\ No newline at end of file
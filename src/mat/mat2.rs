@@ -0,0 +1,472 @@
+use crate::vec::Vec2;
+use super::Matrix;
+use crate::{Real, Scalar};
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+
+pub type Mat2f = Mat2<f32>;
+pub type Mat2d = Mat2<f64>;
+
+/// 2x2 matrix stored as two rows.
+#[derive(Debug, PartialEq, Default, Clone, Copy)]
+#[repr(C)]
+pub struct Mat2<F>
+where
+    F: Scalar,
+{
+    r0: Vec2<F>,
+    r1: Vec2<F>,
+}
+
+impl<F> Mat2<F>
+where
+    F: Scalar,
+{
+    /// Creates new matrix from rows.
+    #[inline]
+    pub const fn new(r0: Vec2<F>, r1: Vec2<F>) -> Self {
+        Self { r0, r1 }
+    }
+
+    /// Creates new matrix from columns.
+    #[inline]
+    pub fn from_columns(c0: Vec2<F>, c1: Vec2<F>) -> Self {
+        Self {
+            r0: Vec2::new(c0.x, c1.x),
+            r1: Vec2::new(c0.y, c1.y),
+        }
+    }
+
+    /// Gets matrix's row by index.
+    /// # Panics
+    /// If index is `>1`.
+    #[inline]
+    pub fn row<const I: usize>(&self) -> Vec2<F> {
+        self.row_at(I)
+    }
+
+    /// Sets matrix's row by index.
+    /// # Panics
+    /// If index is `>1`.
+    #[inline]
+    pub fn set_row<const I: usize>(&mut self, row: Vec2<F>) {
+        self.set_row_at(I, row)
+    }
+
+    /// Gets matrix's column by index.
+    /// # Panics
+    /// If index is `>1`.
+    #[inline]
+    pub fn column<const I: usize>(&self) -> Vec2<F> {
+        self.column_at(I)
+    }
+
+    /// Sets matrix's column by index.
+    /// # Panics
+    /// If index is `>1`.
+    #[inline]
+    pub fn set_column<const I: usize>(&mut self, column: Vec2<F>) {
+        self.set_column_at(I, column)
+    }
+
+    /// Gets matrix's row by runtime index.
+    /// # Panics
+    /// If index is `>1`.
+    #[inline]
+    pub fn row_at(&self, i: usize) -> Vec2<F> {
+        match i {
+            0 => self.r0,
+            1 => self.r1,
+            _ => panic!("Index out of range"),
+        }
+    }
+
+    /// Sets matrix's row by runtime index.
+    /// # Panics
+    /// If index is `>1`.
+    #[inline]
+    pub fn set_row_at(&mut self, i: usize, row: Vec2<F>) {
+        match i {
+            0 => self.r0 = row,
+            1 => self.r1 = row,
+            _ => panic!("Index out of range"),
+        }
+    }
+
+    /// Gets matrix's column by runtime index.
+    /// # Panics
+    /// If index is `>1`.
+    #[inline]
+    pub fn column_at(&self, i: usize) -> Vec2<F> {
+        match i {
+            0 => Vec2::new(self.r0.x, self.r1.x),
+            1 => Vec2::new(self.r0.y, self.r1.y),
+            _ => panic!("Index out of range"),
+        }
+    }
+
+    /// Sets matrix's column by runtime index.
+    /// # Panics
+    /// If index is `>1`.
+    #[inline]
+    pub fn set_column_at(&mut self, i: usize, column: Vec2<F>) {
+        match i {
+            0 => {
+                self.r0.x = column.x;
+                self.r1.x = column.y;
+            }
+            1 => {
+                self.r0.y = column.x;
+                self.r1.y = column.y;
+            }
+            _ => panic!("Index out of range"),
+        }
+    }
+
+    /// Creates new identity matrix.
+    #[inline]
+    pub fn identity() -> Self {
+        Self {
+            r0: Vec2::new(F::one(), F::zero()),
+            r1: Vec2::new(F::zero(), F::one()),
+        }
+    }
+
+    /// Transposes the matrix in place.
+    #[inline]
+    pub fn transpose(&mut self) {
+        *self = self.transposed();
+    }
+
+    /// Returns the transposed copy of the matrix.
+    #[inline]
+    pub fn transposed(&self) -> Self {
+        Matrix::from(*self).transposed().into()
+    }
+
+    /// Computes the trace of the matrix, the sum of its diagonal elements.
+    #[inline]
+    pub fn trace(&self) -> F {
+        Matrix::from(*self).trace()
+    }
+
+    /// Computes the determinant of the matrix.
+    #[inline]
+    pub fn determinant(&self) -> F {
+        self.r0.x * self.r1.y - self.r0.y * self.r1.x
+    }
+
+    /// Computes the inverse of the matrix.
+    /// Returns `None` if the matrix is singular.
+    /// ```
+    /// # use ewq::{mat::Mat2f, vec::Vec2f};
+    /// let m = Mat2f::new(Vec2f::new(2., 0.), Vec2f::new(0., 4.));
+    /// assert_eq!(m.inverse(), Some(Mat2f::new(Vec2f::new(0.5, 0.), Vec2f::new(0., 0.25))));
+    /// ```
+    #[inline]
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        if det == F::zero() {
+            return None;
+        }
+
+        Some(Self {
+            r0: Vec2::new(self.r1.y, -self.r0.y) / det,
+            r1: Vec2::new(-self.r1.x, self.r0.x) / det,
+        })
+    }
+}
+
+impl<F> Mat2<F>
+where
+    F: Real,
+{
+    /// Converts the matrix to the other floating point precision.
+    /// ```
+    /// # use ewq::mat::{Mat2d, Mat2f};
+    /// assert_eq!(Mat2f::identity().cast::<f64>(), Mat2d::identity());
+    /// ```
+    #[inline]
+    pub fn cast<T>(&self) -> Mat2<T>
+    where
+        T: Real,
+    {
+        Mat2::new(self.r0.cast(), self.r1.cast())
+    }
+
+    /// Creates new matrix that rotates vectors counter-clockwise by `angle` radians.
+    /// ```
+    /// # use ewq::{mat::Mat2d, vec::Vec2d};
+    /// let v = Mat2d::from_angle(std::f64::consts::FRAC_PI_2) * Vec2d::new(1., 0.);
+    /// assert!((v - Vec2d::new(0., 1.)).magnitude() < 1e-12);
+    /// ```
+    #[inline]
+    pub fn from_angle(angle: F) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self {
+            r0: Vec2::new(cos, -sin),
+            r1: Vec2::new(sin, cos),
+        }
+    }
+
+    /// Checks if all elements of two matrices differ by no more than `epsilon`.
+    #[inline]
+    pub fn abs_diff_eq(&self, other: &Self, epsilon: F) -> bool {
+        Matrix::from(*self).abs_diff_eq(&Matrix::from(*other), epsilon)
+    }
+
+    /// Checks if all elements of two matrices are equal within `epsilon`
+    /// or within `max_relative` of the larger element magnitude.
+    #[inline]
+    pub fn relative_eq(&self, other: &Self, epsilon: F, max_relative: F) -> bool {
+        Matrix::from(*self).relative_eq(&Matrix::from(*other), epsilon, max_relative)
+    }
+
+    /// Computes the Frobenius norm of the matrix.
+    #[inline]
+    pub fn frobenius_norm(&self) -> F {
+        Matrix::from(*self).frobenius_norm()
+    }
+
+    /// Computes the singular value decomposition of the matrix.
+    /// Returns `(u, s, v)` such that `self = u * diag(s) * v^T`, where `u` and `v` are orthogonal
+    /// and singular values in `s` are non-negative and sorted in descending order.
+    /// ```
+    /// # use ewq::{mat::Mat2d, vec::Vec2d};
+    /// let m = Mat2d::new(Vec2d::new(3., 1.), Vec2d::new(-2., 4.));
+    /// let (u, s, v) = m.svd();
+    /// let d = Mat2d::new(Vec2d::new(s.x, 0.), Vec2d::new(0., s.y));
+    /// assert!((u * d * v.transposed()).abs_diff_eq(&m, 1e-12));
+    /// ```
+    pub fn svd(&self) -> (Self, Vec2<F>, Self) {
+        let two = F::from(2).unwrap();
+        let e = (self.r0.x + self.r1.y) / two;
+        let f = (self.r0.x - self.r1.y) / two;
+        let g = (self.r1.x + self.r0.y) / two;
+        let h = (self.r1.x - self.r0.y) / two;
+
+        let (q, r) = (e.hypot(h), f.hypot(g));
+        let (a1, a2) = (g.atan2(f), h.atan2(e));
+        let (theta, phi) = ((a2 - a1) / two, (a2 + a1) / two);
+
+        let mut u = Self::from_angle(phi);
+        let mut s = Vec2::new(q + r, q - r);
+        if s.y < F::zero() {
+            s.y = -s.y;
+            u.set_column::<1>(-u.column::<1>());
+        }
+        (u, s, Self::from_angle(-theta))
+    }
+}
+
+impl<F> Add for Mat2<F>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            r0: self.r0 + rhs.r0,
+            r1: self.r1 + rhs.r1,
+        }
+    }
+}
+
+impl<F> AddAssign for Mat2<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.r0 = self.r0 + rhs.r0;
+        self.r1 = self.r1 + rhs.r1;
+    }
+}
+
+impl<F> Sub for Mat2<F>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            r0: self.r0 - rhs.r0,
+            r1: self.r1 - rhs.r1,
+        }
+    }
+}
+
+impl<F> SubAssign for Mat2<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.r0 = self.r0 - rhs.r0;
+        self.r1 = self.r1 - rhs.r1;
+    }
+}
+
+impl<F> Mul<F> for Mat2<F>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: F) -> Self::Output {
+        Self {
+            r0: self.r0 * rhs,
+            r1: self.r1 * rhs,
+        }
+    }
+}
+
+impl<F> MulAssign<F> for Mat2<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn mul_assign(&mut self, rhs: F) {
+        self.r0 = self.r0 * rhs;
+        self.r1 = self.r1 * rhs;
+    }
+}
+
+impl<F> Div<F> for Mat2<F>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: F) -> Self::Output {
+        Self {
+            r0: self.r0 / rhs,
+            r1: self.r1 / rhs,
+        }
+    }
+}
+
+impl<F> DivAssign<F> for Mat2<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn div_assign(&mut self, rhs: F) {
+        self.r0 = self.r0 / rhs;
+        self.r1 = self.r1 / rhs;
+    }
+}
+
+impl<F> Neg for Mat2<F>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self {
+            r0: -self.r0,
+            r1: -self.r1,
+        }
+    }
+}
+
+impl<F> Mul<Vec2<F>> for Mat2<F>
+where
+    F: Scalar,
+{
+    type Output = Vec2<F>;
+
+    #[inline]
+    fn mul(self, rhs: Vec2<F>) -> Self::Output {
+        Vec2 {
+            x: rhs.dot(self.r0),
+            y: rhs.dot(self.r1),
+        }
+    }
+}
+
+impl<F> Mul for Mat2<F>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        (Matrix::from(self) * Matrix::from(rhs)).into()
+    }
+}
+
+impl<F> Index<(usize, usize)> for Mat2<F>
+where
+    F: Scalar,
+{
+    type Output = F;
+
+    /// Gets the element by `(row, column)` index.
+    /// # Panics
+    /// If index is out of range.
+    #[inline]
+    fn index(&self, (row, column): (usize, usize)) -> &Self::Output {
+        let row = match row {
+            0 => &self.r0,
+            1 => &self.r1,
+            _ => panic!("Index out of range"),
+        };
+        match column {
+            0 => &row.x,
+            1 => &row.y,
+            _ => panic!("Index out of range"),
+        }
+    }
+}
+
+impl<F> IndexMut<(usize, usize)> for Mat2<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut Self::Output {
+        let row = match row {
+            0 => &mut self.r0,
+            1 => &mut self.r1,
+            _ => panic!("Index out of range"),
+        };
+        match column {
+            0 => &mut row.x,
+            1 => &mut row.y,
+            _ => panic!("Index out of range"),
+        }
+    }
+}
+
+impl<F> Display for Mat2<F>
+where
+    F: Scalar + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        super::fmt_grid(f, 2, 2, |i, j| self[(i, j)])
+    }
+}
+
+/// Generates constants for the floating point matrix types.
+macro_rules! mat2_consts {
+    ($($f:ty),+) => {$(
+        impl Mat2<$f> {
+            /// Matrix with all elements set to `0`.
+            pub const ZERO: Self = Self::new(Vec2::new(0., 0.), Vec2::new(0., 0.));
+            /// Identity matrix.
+            pub const IDENTITY: Self = Self::new(Vec2::new(1., 0.), Vec2::new(0., 1.));
+        }
+    )+};
+}
+
+mat2_consts!(f32, f64);
@@ -0,0 +1,712 @@
+use crate::vec::{Vec3, Vec4};
+use crate::Quat;
+use super::{Mat3x4, Matrix};
+use crate::{Real, Scalar};
+use std::cmp::Ordering;
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+
+pub type Mat3f = Mat3<f32>;
+pub type Mat3d = Mat3<f64>;
+
+/// 3x3 matrix stored as three rows.
+#[derive(Debug, PartialEq, Default, Clone, Copy)]
+#[repr(C)]
+pub struct Mat3<F>
+where
+    F: Scalar,
+{
+    r0: Vec3<F>,
+    r1: Vec3<F>,
+    r2: Vec3<F>,
+}
+
+impl<F> Mat3<F>
+where
+    F: Scalar,
+{
+    /// Creates new matrix from rows.
+    #[inline]
+    pub const fn new(r0: Vec3<F>, r1: Vec3<F>, r2: Vec3<F>) -> Self {
+        Self { r0, r1, r2 }
+    }
+
+    /// Creates new matrix from columns.
+    #[inline]
+    pub fn from_columns(c0: Vec3<F>, c1: Vec3<F>, c2: Vec3<F>) -> Self {
+        Self {
+            r0: Vec3::new(c0.x, c1.x, c2.x),
+            r1: Vec3::new(c0.y, c1.y, c2.y),
+            r2: Vec3::new(c0.z, c1.z, c2.z),
+        }
+    }
+
+    /// Gets matrix's row by index.
+    /// # Panics
+    /// If index is `>2`.
+    #[inline]
+    pub fn row<const I: usize>(&self) -> Vec3<F> {
+        self.row_at(I)
+    }
+
+    /// Sets matrix's row by index.
+    /// # Panics
+    /// If index is `>2`.
+    #[inline]
+    pub fn set_row<const I: usize>(&mut self, row: Vec3<F>) {
+        self.set_row_at(I, row)
+    }
+
+    /// Gets matrix's column by index.
+    /// # Panics
+    /// If index is `>2`.
+    #[inline]
+    pub fn column<const I: usize>(&self) -> Vec3<F> {
+        self.column_at(I)
+    }
+
+    /// Sets matrix's column by index.
+    /// # Panics
+    /// If index is `>2`.
+    #[inline]
+    pub fn set_column<const I: usize>(&mut self, column: Vec3<F>) {
+        self.set_column_at(I, column)
+    }
+
+    /// Gets matrix's row by runtime index.
+    /// # Panics
+    /// If index is `>2`.
+    #[inline]
+    pub fn row_at(&self, i: usize) -> Vec3<F> {
+        match i {
+            0 => self.r0,
+            1 => self.r1,
+            2 => self.r2,
+            _ => panic!("Index out of range"),
+        }
+    }
+
+    /// Sets matrix's row by runtime index.
+    /// # Panics
+    /// If index is `>2`.
+    #[inline]
+    pub fn set_row_at(&mut self, i: usize, row: Vec3<F>) {
+        match i {
+            0 => self.r0 = row,
+            1 => self.r1 = row,
+            2 => self.r2 = row,
+            _ => panic!("Index out of range"),
+        }
+    }
+
+    /// Gets matrix's column by runtime index.
+    /// # Panics
+    /// If index is `>2`.
+    #[inline]
+    pub fn column_at(&self, i: usize) -> Vec3<F> {
+        match i {
+            0 => Vec3::new(self.r0.x, self.r1.x, self.r2.x),
+            1 => Vec3::new(self.r0.y, self.r1.y, self.r2.y),
+            2 => Vec3::new(self.r0.z, self.r1.z, self.r2.z),
+            _ => panic!("Index out of range"),
+        }
+    }
+
+    /// Sets matrix's column by runtime index.
+    /// # Panics
+    /// If index is `>2`.
+    #[inline]
+    pub fn set_column_at(&mut self, i: usize, column: Vec3<F>) {
+        match i {
+            0 => {
+                self.r0.x = column.x;
+                self.r1.x = column.y;
+                self.r2.x = column.z;
+            }
+            1 => {
+                self.r0.y = column.x;
+                self.r1.y = column.y;
+                self.r2.y = column.z;
+            }
+            2 => {
+                self.r0.z = column.x;
+                self.r1.z = column.y;
+                self.r2.z = column.z;
+            }
+            _ => panic!("Index out of range"),
+        }
+    }
+
+    /// Creates new identity matrix.
+    #[inline]
+    pub fn identity() -> Self {
+        Self {
+            r0: Vec3::new(F::one(), F::zero(), F::zero()),
+            r1: Vec3::new(F::zero(), F::one(), F::zero()),
+            r2: Vec3::new(F::zero(), F::zero(), F::one()),
+        }
+    }
+
+    /// Transposes the matrix in place.
+    #[inline]
+    pub fn transpose(&mut self) {
+        *self = self.transposed();
+    }
+
+    /// Returns the transposed copy of the matrix.
+    #[inline]
+    pub fn transposed(&self) -> Self {
+        Matrix::from(*self).transposed().into()
+    }
+
+    /// Computes the trace of the matrix, the sum of its diagonal elements.
+    #[inline]
+    pub fn trace(&self) -> F {
+        Matrix::from(*self).trace()
+    }
+
+    /// Computes the determinant of the matrix.
+    #[inline]
+    pub fn determinant(&self) -> F {
+        self.r0.triple(self.r1, self.r2)
+    }
+
+    /// Computes the inverse of the matrix.
+    /// Returns `None` if the matrix is singular.
+    /// ```
+    /// # use ewq::{mat::Mat3f, vec::Vec3f};
+    /// let m = Mat3f::new(
+    ///     Vec3f::new(2., 0., 0.),
+    ///     Vec3f::new(0., 0., 1.),
+    ///     Vec3f::new(0., 4., 0.),
+    /// );
+    /// assert_eq!(m * m.inverse().unwrap(), Mat3f::identity());
+    /// ```
+    #[inline]
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        if det == F::zero() {
+            return None;
+        }
+
+        Some(Self::from_columns(
+            self.r1.cross(self.r2) / det,
+            self.r2.cross(self.r0) / det,
+            self.r0.cross(self.r1) / det,
+        ))
+    }
+}
+
+impl<F> Mat3<F>
+where
+    F: Real,
+{
+    /// Converts the matrix to the other floating point precision.
+    /// ```
+    /// # use ewq::mat::{Mat3d, Mat3f};
+    /// assert_eq!(Mat3f::identity().cast::<f64>(), Mat3d::identity());
+    /// ```
+    #[inline]
+    pub fn cast<T>(&self) -> Mat3<T>
+    where
+        T: Real,
+    {
+        Mat3::new(self.r0.cast(), self.r1.cast(), self.r2.cast())
+    }
+
+    /// Checks if all elements of two matrices differ by no more than `epsilon`.
+    #[inline]
+    pub fn abs_diff_eq(&self, other: &Self, epsilon: F) -> bool {
+        Matrix::from(*self).abs_diff_eq(&Matrix::from(*other), epsilon)
+    }
+
+    /// Checks if all elements of two matrices are equal within `epsilon`
+    /// or within `max_relative` of the larger element magnitude.
+    /// ```
+    /// # use ewq::mat::Mat3d;
+    /// let m = Mat3d::identity() * 1e8;
+    /// assert!(m.relative_eq(&(m * (1. + 1e-12)), 0., 1e-9));
+    /// ```
+    #[inline]
+    pub fn relative_eq(&self, other: &Self, epsilon: F, max_relative: F) -> bool {
+        Matrix::from(*self).relative_eq(&Matrix::from(*other), epsilon, max_relative)
+    }
+
+    /// Creates new matrix that represents a rotation around `X` axis by `angle` radians.
+    #[inline]
+    pub fn rotation_x(angle: F) -> Self {
+        let (s, c) = angle.sin_cos();
+        Self {
+            r0: Vec3::new(F::one(), F::zero(), F::zero()),
+            r1: Vec3::new(F::zero(), c, -s),
+            r2: Vec3::new(F::zero(), s, c),
+        }
+    }
+
+    /// Creates new matrix that represents a rotation around `Y` axis by `angle` radians.
+    #[inline]
+    pub fn rotation_y(angle: F) -> Self {
+        let (s, c) = angle.sin_cos();
+        Self {
+            r0: Vec3::new(c, F::zero(), s),
+            r1: Vec3::new(F::zero(), F::one(), F::zero()),
+            r2: Vec3::new(-s, F::zero(), c),
+        }
+    }
+
+    /// Creates new matrix that represents a rotation around `Z` axis by `angle` radians.
+    /// ```
+    /// # use ewq::{mat::Mat3d, vec::Vec3d};
+    /// let m = Mat3d::rotation_z(std::f64::consts::FRAC_PI_2);
+    /// assert!((m * Vec3d::forward() - Vec3d::left()).magnitude() < 1e-12);
+    /// ```
+    #[inline]
+    pub fn rotation_z(angle: F) -> Self {
+        let (s, c) = angle.sin_cos();
+        Self {
+            r0: Vec3::new(c, -s, F::zero()),
+            r1: Vec3::new(s, c, F::zero()),
+            r2: Vec3::new(F::zero(), F::zero(), F::one()),
+        }
+    }
+
+    /// Creates new matrix that represents a rotation around `axis` by `angle` radians.
+    /// Method can accept denormalized input.
+    /// ```
+    /// # use ewq::{mat::Mat3d, vec::Vec3d};
+    /// let m = Mat3d::from_axis_angle(Vec3d::new(0., 0., 2.), std::f64::consts::FRAC_PI_2);
+    /// assert!((m * Vec3d::new(1., 0., 0.) - Vec3d::new(0., 1., 0.)).magnitude() < 1e-12);
+    /// ```
+    #[inline]
+    pub fn from_axis_angle(axis: Vec3<F>, angle: F) -> Self {
+        let Vec3 { x, y, z } = axis.normalized();
+        let (s, c) = angle.sin_cos();
+        let t = F::one() - c;
+
+        Self {
+            r0: Vec3::new(c + x * x * t, x * y * t - z * s, x * z * t + y * s),
+            r1: Vec3::new(x * y * t + z * s, c + y * y * t, y * z * t - x * s),
+            r2: Vec3::new(x * z * t - y * s, y * z * t + x * s, c + z * z * t),
+        }
+    }
+
+    /// Creates new rotation matrix from the quaternion.
+    /// `q` is expected to be normalized.
+    /// ```
+    /// # use ewq::{mat::Mat3d, vec::Vec3d, Quatd};
+    /// let q = Quatd::new_axis_rotation(Vec3d::new(1., 2., 3.), 0.5);
+    /// let v = Vec3d::new(3., 2., 1.);
+    /// assert!((Mat3d::from_quat(q) * v - q.rotate(v)).magnitude() < 1e-12);
+    /// ```
+    #[inline]
+    pub fn from_quat(q: Quat<F>) -> Self {
+        let two = F::from(2).unwrap();
+        let Vec3 { x, y, z } = q.v;
+        let w = q.w;
+
+        let (xx, yy, zz) = (x * x, y * y, z * z);
+        let (xy, xz, yz) = (x * y, x * z, y * z);
+        let (wx, wy, wz) = (w * x, w * y, w * z);
+
+        Self {
+            r0: Vec3::new(F::one() - two * (yy + zz), two * (xy - wz), two * (xz + wy)),
+            r1: Vec3::new(two * (xy + wz), F::one() - two * (xx + zz), two * (yz - wx)),
+            r2: Vec3::new(two * (xz - wy), two * (yz + wx), F::one() - two * (xx + yy)),
+        }
+    }
+
+    /// Computes the Frobenius norm of the matrix.
+    /// ```
+    /// # use ewq::mat::Mat3f;
+    /// assert_eq!((Mat3f::identity() * 2.).frobenius_norm(), 12f32.sqrt());
+    /// ```
+    #[inline]
+    pub fn frobenius_norm(&self) -> F {
+        Matrix::from(*self).frobenius_norm()
+    }
+
+    /// Solves linear system `self * x = b` for `x`.
+    /// Returns `None` if the matrix is singular. See [`Matrix::solve`].
+    /// ```
+    /// # use ewq::{mat::Mat3d, vec::Vec3d};
+    /// let m = Mat3d::new(
+    ///     Vec3d::new(0., 2., 0.),
+    ///     Vec3d::new(1., 0., 0.),
+    ///     Vec3d::new(0., 0., 4.),
+    /// );
+    /// assert_eq!(m.solve(Vec3d::new(2., 3., 8.)), Some(Vec3d::new(3., 1., 2.)));
+    /// ```
+    #[inline]
+    pub fn solve(&self, b: Vec3<F>) -> Option<Vec3<F>> {
+        let [x, y, z] = Matrix::from(*self).solve([b.x, b.y, b.z])?;
+        Some(Vec3::new(x, y, z))
+    }
+
+    /// Computes the singular value decomposition of the matrix using one-sided Jacobi method.
+    /// Returns `(u, s, v)` such that `self = u * diag(s) * v^T`, where `u` and `v` are orthogonal
+    /// and singular values in `s` are non-negative and sorted in descending order.
+    /// ```
+    /// # use ewq::{mat::Mat3d, vec::Vec3d};
+    /// let m = Mat3d::new(
+    ///     Vec3d::new(3., 1., 0.),
+    ///     Vec3d::new(-2., 4., 1.),
+    ///     Vec3d::new(1., 0., 5.),
+    /// );
+    /// let (u, s, v) = m.svd();
+    /// let d = Mat3d::new(Vec3d::new(s.x, 0., 0.), Vec3d::new(0., s.y, 0.), Vec3d::new(0., 0., s.z));
+    /// assert!((u * d * v.transposed()).abs_diff_eq(&m, 1e-12));
+    /// assert!((u.transposed() * u).abs_diff_eq(&Mat3d::identity(), 1e-12));
+    /// ```
+    pub fn svd(&self) -> (Self, Vec3<F>, Self) {
+        const MAX_SWEEPS: usize = 32;
+
+        let two = F::from(2).unwrap();
+        let mut a = [self.column::<0>(), self.column::<1>(), self.column::<2>()];
+        let mut v = [
+            Vec3::new(F::one(), F::zero(), F::zero()),
+            Vec3::new(F::zero(), F::one(), F::zero()),
+            Vec3::new(F::zero(), F::zero(), F::one()),
+        ];
+
+        for _ in 0..MAX_SWEEPS {
+            let mut rotated = false;
+            for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+                let alpha = a[p].sqrt_magnitude();
+                let beta = a[q].sqrt_magnitude();
+                let gamma = a[p].dot(a[q]);
+                if gamma.abs() <= F::epsilon() * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
+
+                let zeta = (beta - alpha) / (two * gamma);
+                let t = zeta.signum() / (zeta.abs() + (F::one() + zeta * zeta).sqrt());
+                let c = F::one() / (F::one() + t * t).sqrt();
+                let s = c * t;
+
+                let (ap, aq) = (a[p], a[q]);
+                a[p] = ap * c - aq * s;
+                a[q] = ap * s + aq * c;
+
+                let (vp, vq) = (v[p], v[q]);
+                v[p] = vp * c - vq * s;
+                v[q] = vp * s + vq * c;
+            }
+            if !rotated {
+                break;
+            }
+        }
+
+        let norms = [a[0].magnitude(), a[1].magnitude(), a[2].magnitude()];
+        let mut order = [0, 1, 2];
+        order.sort_by(|&i, &j| norms[j].partial_cmp(&norms[i]).unwrap_or(Ordering::Equal));
+        let [i0, i1, i2] = order;
+        let s = Vec3::new(norms[i0], norms[i1], norms[i2]);
+
+        let tolerance = s.x * F::epsilon() * F::from(3).unwrap();
+        let u0 = if s.x > F::zero() {
+            a[i0] / s.x
+        } else {
+            Vec3::new(F::one(), F::zero(), F::zero())
+        };
+        let u1 = if s.y > tolerance {
+            a[i1] / s.y
+        } else if u0.x.abs() < u0.y.abs() {
+            u0.cross(Vec3::new(F::one(), F::zero(), F::zero()))
+                .normalized()
+        } else {
+            u0.cross(Vec3::new(F::zero(), F::one(), F::zero()))
+                .normalized()
+        };
+        let u2 = if s.z > tolerance {
+            a[i2] / s.z
+        } else {
+            u0.cross(u1)
+        };
+
+        (
+            Self::from_columns(u0, u1, u2),
+            s,
+            Self::from_columns(v[i0], v[i1], v[i2]),
+        )
+    }
+}
+
+impl<F> Add for Mat3<F>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            r0: self.r0 + rhs.r0,
+            r1: self.r1 + rhs.r1,
+            r2: self.r2 + rhs.r2,
+        }
+    }
+}
+
+impl<F> AddAssign for Mat3<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.r0 = self.r0 + rhs.r0;
+        self.r1 = self.r1 + rhs.r1;
+        self.r2 = self.r2 + rhs.r2;
+    }
+}
+
+impl<F> Sub for Mat3<F>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            r0: self.r0 - rhs.r0,
+            r1: self.r1 - rhs.r1,
+            r2: self.r2 - rhs.r2,
+        }
+    }
+}
+
+impl<F> SubAssign for Mat3<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.r0 = self.r0 - rhs.r0;
+        self.r1 = self.r1 - rhs.r1;
+        self.r2 = self.r2 - rhs.r2;
+    }
+}
+
+impl<F> Mul<F> for Mat3<F>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: F) -> Self::Output {
+        Self {
+            r0: self.r0 * rhs,
+            r1: self.r1 * rhs,
+            r2: self.r2 * rhs,
+        }
+    }
+}
+
+impl<F> MulAssign<F> for Mat3<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn mul_assign(&mut self, rhs: F) {
+        self.r0 = self.r0 * rhs;
+        self.r1 = self.r1 * rhs;
+        self.r2 = self.r2 * rhs;
+    }
+}
+
+impl<F> Div<F> for Mat3<F>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: F) -> Self::Output {
+        Self {
+            r0: self.r0 / rhs,
+            r1: self.r1 / rhs,
+            r2: self.r2 / rhs,
+        }
+    }
+}
+
+impl<F> DivAssign<F> for Mat3<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn div_assign(&mut self, rhs: F) {
+        self.r0 = self.r0 / rhs;
+        self.r1 = self.r1 / rhs;
+        self.r2 = self.r2 / rhs;
+    }
+}
+
+impl<F> Neg for Mat3<F>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self {
+            r0: -self.r0,
+            r1: -self.r1,
+            r2: -self.r2,
+        }
+    }
+}
+
+impl<F> Mul<Vec3<F>> for Mat3<F>
+where
+    F: Scalar,
+{
+    type Output = Vec3<F>;
+
+    /// Transforms the vector by the matrix.
+    /// ```
+    /// # use ewq::{mat::Mat3f, vec::Vec3f};
+    /// let m = Mat3f::from_columns(
+    ///     Vec3f::new(0., 1., 0.),
+    ///     Vec3f::new(-1., 0., 0.),
+    ///     Vec3f::new(0., 0., 1.),
+    /// );
+    /// assert_eq!(m * Vec3f::new(1., 0., 0.), Vec3f::new(0., 1., 0.));
+    /// ```
+    #[inline]
+    fn mul(self, rhs: Vec3<F>) -> Self::Output {
+        Vec3 {
+            x: rhs.dot(self.r0),
+            y: rhs.dot(self.r1),
+            z: rhs.dot(self.r2),
+        }
+    }
+}
+
+impl<F> From<Mat3x4<F>> for Mat3<F>
+where
+    F: Scalar,
+{
+    /// Takes the upper-left 3x3 block of the matrix.
+    #[inline]
+    fn from(m: Mat3x4<F>) -> Self {
+        Self {
+            r0: m.row::<0>().reduce(),
+            r1: m.row::<1>().reduce(),
+            r2: m.row::<2>().reduce(),
+        }
+    }
+}
+
+impl<F> From<Mat3<F>> for Mat3x4<F>
+where
+    F: Scalar,
+{
+    /// Expands matrix with zero translation column.
+    #[inline]
+    fn from(m: Mat3<F>) -> Self {
+        Self::new(
+            Vec4::from_vec3(m.r0, F::zero()),
+            Vec4::from_vec3(m.r1, F::zero()),
+            Vec4::from_vec3(m.r2, F::zero()),
+        )
+    }
+}
+
+impl<F> Mul for Mat3<F>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        (Matrix::from(self) * Matrix::from(rhs)).into()
+    }
+}
+
+impl<F> Index<(usize, usize)> for Mat3<F>
+where
+    F: Scalar,
+{
+    type Output = F;
+
+    /// Gets the element by `(row, column)` index.
+    /// # Panics
+    /// If index is out of range.
+    /// ```
+    /// # use ewq::mat::Mat3f;
+    /// let mut m = Mat3f::identity();
+    /// m[(0, 2)] = 5.;
+    /// assert_eq!(m[(0, 2)], 5.);
+    /// assert_eq!(m[(1, 1)], 1.);
+    /// ```
+    #[inline]
+    fn index(&self, (row, column): (usize, usize)) -> &Self::Output {
+        let row = match row {
+            0 => &self.r0,
+            1 => &self.r1,
+            2 => &self.r2,
+            _ => panic!("Index out of range"),
+        };
+        match column {
+            0 => &row.x,
+            1 => &row.y,
+            2 => &row.z,
+            _ => panic!("Index out of range"),
+        }
+    }
+}
+
+impl<F> IndexMut<(usize, usize)> for Mat3<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut Self::Output {
+        let row = match row {
+            0 => &mut self.r0,
+            1 => &mut self.r1,
+            2 => &mut self.r2,
+            _ => panic!("Index out of range"),
+        };
+        match column {
+            0 => &mut row.x,
+            1 => &mut row.y,
+            2 => &mut row.z,
+            _ => panic!("Index out of range"),
+        }
+    }
+}
+
+impl<F> Display for Mat3<F>
+where
+    F: Scalar + Display,
+{
+    /// Formats the matrix as an aligned grid.
+    /// ```
+    /// # use ewq::mat::Mat3f;
+    /// let m = Mat3f::identity() * 10.;
+    /// assert_eq!(format!("{:.1}", m), "[ 10.0  0.0  0.0 ]\n[  0.0 10.0  0.0 ]\n[  0.0  0.0 10.0 ]");
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        super::fmt_grid(f, 3, 3, |i, j| self[(i, j)])
+    }
+}
+
+/// Generates constants for the floating point matrix types.
+macro_rules! mat3_consts {
+    ($($f:ty),+) => {$(
+        impl Mat3<$f> {
+            /// Matrix with all elements set to `0`.
+            pub const ZERO: Self = Self::new(Vec3::<$f>::ZERO, Vec3::<$f>::ZERO, Vec3::<$f>::ZERO);
+            /// Identity matrix.
+            pub const IDENTITY: Self = Self::new(Vec3::<$f>::X, Vec3::<$f>::Y, Vec3::<$f>::Z);
+        }
+    )+};
+}
+
+mat3_consts!(f32, f64);
@@ -1,6 +1,9 @@
 use crate::vec::{Vec3, Vec4};
-use num_traits::Float;
-use std::ops::Mul;
+use crate::{Quat, QuatT};
+use super::{Mat3, Mat4, Mat4x3, Matrix};
+use crate::{Real, Scalar};
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
 
 pub type Mat3x4f = Mat3x4<f32>;
 pub type Mat3x4d = Mat3x4<f64>;
@@ -9,7 +12,7 @@ pub type Mat3x4d = Mat3x4<f64>;
 #[repr(C)]
 pub struct Mat3x4<F>
 where
-    F: Float,
+    F: Scalar,
 {
     r0: Vec4<F>,
     r1: Vec4<F>,
@@ -18,11 +21,11 @@ where
 
 impl<F> Mat3x4<F>
 where
-    F: Float,
+    F: Scalar,
 {
     /// Creates new matrix from rows.
     #[inline]
-    pub fn new(r0: Vec4<F>, r1: Vec4<F>, r2: Vec4<F>) -> Self {
+    pub const fn new(r0: Vec4<F>, r1: Vec4<F>, r2: Vec4<F>) -> Self {
         Self { r0, r1, r2 }
     }
 
@@ -31,7 +34,45 @@ where
     /// If index is `>2`.
     #[inline]
     pub fn row<const I: usize>(&self) -> Vec4<F> {
-        match I {
+        self.row_at(I)
+    }
+
+    /// Sets matrix's row by index.
+    /// # Panics
+    /// If index is `>2`.
+    #[inline]
+    pub fn set_row<const I: usize>(&mut self, row: Vec4<F>) {
+        self.set_row_at(I, row)
+    }
+
+    /// Gets matrix's column by index.
+    /// # Panics
+    /// If index is `>3`.
+    #[inline]
+    pub fn column<const I: usize>(&self) -> Vec3<F> {
+        self.column_at(I)
+    }
+
+    /// Sets matrix's column by index.
+    /// # Panics
+    /// If index is `>3`.
+    #[inline]
+    pub fn set_column<const I: usize>(&mut self, column: Vec3<F>) {
+        self.set_column_at(I, column)
+    }
+
+    /// Gets matrix's row by runtime index.
+    /// # Panics
+    /// If index is `>2`.
+    /// ```
+    /// # use ewq::{mat::Mat3x4f, vec::Vec4f};
+    /// let m = Mat3x4f::identity();
+    /// let sum = (0..3).fold(Vec4f::default(), |acc, i| acc + m.row_at(i));
+    /// assert_eq!(sum, Vec4f::new(1., 1., 1., 0.));
+    /// ```
+    #[inline]
+    pub fn row_at(&self, i: usize) -> Vec4<F> {
+        match i {
             0 => self.r0,
             1 => self.r1,
             2 => self.r2,
@@ -39,25 +80,25 @@ where
         }
     }
 
-    /// Sets matrix's row by index.
+    /// Sets matrix's row by runtime index.
     /// # Panics
     /// If index is `>2`.
     #[inline]
-    pub fn set_row<const I: usize>(&mut self, row: Vec4<F>) {
-        match I {
+    pub fn set_row_at(&mut self, i: usize, row: Vec4<F>) {
+        match i {
             0 => self.r0 = row,
             1 => self.r1 = row,
             2 => self.r2 = row,
-            _ => panic!("Index out of range")
+            _ => panic!("Index out of range"),
         }
     }
 
-    /// Gets matrix's column by index.
+    /// Gets matrix's column by runtime index.
     /// # Panics
     /// If index is `>3`.
     #[inline]
-    pub fn column<const I: usize>(&self) -> Vec3<F> {
-        match I {
+    pub fn column_at(&self, i: usize) -> Vec3<F> {
+        match i {
             0 => Vec3::new(self.r0.x, self.r1.x, self.r2.x),
             1 => Vec3::new(self.r0.y, self.r1.y, self.r2.y),
             2 => Vec3::new(self.r0.z, self.r1.z, self.r2.z),
@@ -66,12 +107,12 @@ where
         }
     }
 
-    /// Sets matrix's column by index.
+    /// Sets matrix's column by runtime index.
     /// # Panics
     /// If index is `>3`.
     #[inline]
-    pub fn set_column<const I: usize>(&mut self, column: Vec3<F>) {
-        match I {
+    pub fn set_column_at(&mut self, i: usize, column: Vec3<F>) {
+        match i {
             0 => {
                 self.r0.x = column.x;
                 self.r1.x = column.y;
@@ -105,11 +146,322 @@ where
             r2: Vec4::new(F::zero(), F::zero(), F::one(), F::zero()),
         }
     }
+
+    /// Transposes the upper-left 3x3 block of the matrix in place, leaving the last column untouched.
+    #[inline]
+    pub fn transpose_block(&mut self) {
+        *self = self.transposed_block();
+    }
+
+    /// Returns the copy of the matrix with transposed upper-left 3x3 block.
+    /// The last column is left untouched.
+    #[inline]
+    pub fn transposed_block(&self) -> Self {
+        Self {
+            r0: Vec4::new(self.r0.x, self.r1.x, self.r2.x, self.r0.w),
+            r1: Vec4::new(self.r0.y, self.r1.y, self.r2.y, self.r1.w),
+            r2: Vec4::new(self.r0.z, self.r1.z, self.r2.z, self.r2.w),
+        }
+    }
+
+    /// Computes the determinant of the upper-left 3x3 block of the matrix.
+    #[inline]
+    pub fn determinant(&self) -> F {
+        self.r0.reduce().triple(self.r1.reduce(), self.r2.reduce())
+    }
+
+    /// Returns the transposed copy of the matrix.
+    #[inline]
+    pub fn transposed(&self) -> Mat4x3<F> {
+        Matrix::from(*self).transposed()
+    }
+
+    /// Computes the inverse of the affine transformation.
+    /// Returns `None` if the upper-left 3x3 block is singular.
+    /// ```
+    /// # use ewq::{mat::Mat3x4f, vec::{Vec3f, Vec4f}, Quatf};
+    /// let m = Mat3x4f::from_trs(Vec3f::new(1., 2., 3.), Quatf::identity(), Vec3f::new(2., 4., 8.));
+    /// let v = Vec4f::new(4., 5., 6., 1.) * m;
+    /// assert_eq!(Vec4f::from_vec3(v, 1.) * m.inverse_affine().unwrap(), Vec3f::new(4., 5., 6.));
+    /// ```
+    #[inline]
+    pub fn inverse_affine(&self) -> Option<Self> {
+        let inv = Mat3::from(*self).inverse()?;
+        let t = -(inv * self.column::<3>());
+        let (r0, r1, r2) = (inv.row::<0>(), inv.row::<1>(), inv.row::<2>());
+        Some(Self {
+            r0: Vec4::from_vec3(r0, t.x),
+            r1: Vec4::from_vec3(r1, t.y),
+            r2: Vec4::from_vec3(r2, t.z),
+        })
+    }
+
+    /// Computes the matrix for transforming normals, which is the inverse transpose of the
+    /// upper-left 3x3 block. Returns `None` if the block is singular.
+    /// ```
+    /// # use ewq::{mat::Mat3x4f, vec::Vec3f, Quatf};
+    /// let m = Mat3x4f::from_trs(Vec3f::zero(), Quatf::identity(), Vec3f::new(1., 2., 4.));
+    /// assert_eq!(m.normal_matrix().unwrap() * Vec3f::one(), Vec3f::new(1., 0.5, 0.25));
+    /// ```
+    #[inline]
+    pub fn normal_matrix(&self) -> Option<Mat3<F>> {
+        Mat3::from(*self).inverse().map(|m| m.transposed())
+    }
+}
+
+impl<F> Mat3x4<F>
+where
+    F: Real,
+{
+    /// Converts the matrix to the other floating point precision.
+    /// ```
+    /// # use ewq::mat::{Mat3x4d, Mat3x4f};
+    /// assert!(Mat3x4f::identity().cast::<f64>().abs_diff_eq(&Mat3x4d::identity(), 0.));
+    /// ```
+    #[inline]
+    pub fn cast<T>(&self) -> Mat3x4<T>
+    where
+        T: Real,
+    {
+        Mat3x4::new(self.r0.cast(), self.r1.cast(), self.r2.cast())
+    }
+
+    /// Checks if all elements of two matrices differ by no more than `epsilon`.
+    #[inline]
+    pub fn abs_diff_eq(&self, other: &Self, epsilon: F) -> bool {
+        Matrix::from(*self).abs_diff_eq(&Matrix::from(*other), epsilon)
+    }
+
+    /// Checks if all elements of two matrices are equal within `epsilon`
+    /// or within `max_relative` of the larger element magnitude.
+    #[inline]
+    pub fn relative_eq(&self, other: &Self, epsilon: F, max_relative: F) -> bool {
+        Matrix::from(*self).relative_eq(&Matrix::from(*other), epsilon, max_relative)
+    }
+
+    /// Creates new affine matrix that scales, then rotates and then translates vectors.
+    /// `rotation` is expected to be normalized.
+    /// ```
+    /// # use ewq::{mat::Mat3x4f, vec::{Vec3f, Vec4f}, Quatf};
+    /// let m = Mat3x4f::from_trs(Vec3f::new(1., 2., 3.), Quatf::identity(), Vec3f::new(2., 2., 2.));
+    /// assert_eq!(Vec4f::new(1., 1., 1., 1.) * m, Vec3f::new(3., 4., 5.));
+    /// ```
+    #[inline]
+    pub fn from_trs(translation: Vec3<F>, rotation: Quat<F>, scale: Vec3<F>) -> Self {
+        let m = Mat3::from_quat(rotation);
+        let (r0, r1, r2) = (m.row::<0>(), m.row::<1>(), m.row::<2>());
+        Self {
+            r0: Vec4::new(r0.x * scale.x, r0.y * scale.y, r0.z * scale.z, translation.x),
+            r1: Vec4::new(r1.x * scale.x, r1.y * scale.y, r1.z * scale.z, translation.y),
+            r2: Vec4::new(r2.x * scale.x, r2.y * scale.y, r2.z * scale.z, translation.z),
+        }
+    }
+
+    /// Creates new affine matrix from rotation and translation.
+    #[inline]
+    pub fn from_quat_t(qt: &QuatT<F>) -> Self {
+        Self::from_trs(qt.t, qt.q, Vec3::one())
+    }
+
+    /// Decomposes affine matrix into translation, rotation and scale.
+    /// Negative determinant is represented by the negative scale along X axis.
+    /// ```
+    /// # use ewq::{mat::Mat3x4d, vec::Vec3d, Quatd};
+    /// let q = Quatd::new_axis_rotation(Vec3d::new(1., 2., 3.), 0.5);
+    /// let (t, r, s) = Mat3x4d::from_trs(Vec3d::new(1., 2., 3.), q, Vec3d::new(2., 3., 4.)).decompose();
+    /// assert!((t - Vec3d::new(1., 2., 3.)).magnitude() < 1e-12);
+    /// assert!((r - q).norm() < 1e-12);
+    /// assert!((s - Vec3d::new(2., 3., 4.)).magnitude() < 1e-12);
+    /// ```
+    pub fn decompose(&self) -> (Vec3<F>, Quat<F>, Vec3<F>) {
+        let translation = self.column::<3>();
+        let (c0, c1, c2) = (self.column::<0>(), self.column::<1>(), self.column::<2>());
+
+        let mut scale = Vec3::new(c0.magnitude(), c1.magnitude(), c2.magnitude());
+        if self.determinant() < F::zero() {
+            scale.x = -scale.x;
+        }
+
+        let rotation = Quat::from_mat3(&Mat3::from_columns(
+            c0 / scale.x,
+            c1 / scale.y,
+            c2 / scale.z,
+        ));
+        (translation, rotation, scale)
+    }
+
+    /// Creates new right handed view matrix.
+    /// Same as [`Mat3x4::look_at_rh`].
+    #[inline]
+    pub fn look_at(eye: Vec3<F>, target: Vec3<F>, up: Vec3<F>) -> Self {
+        Self::look_at_rh(eye, target, up)
+    }
+
+    /// Creates new right handed view matrix for the camera at `eye` looking at `target`.
+    /// In view space the camera looks towards `-Z` with `+Y` being up.
+    /// ```
+    /// # use ewq::{mat::Mat3x4f, vec::{Vec3f, Vec4f}};
+    /// let m = Mat3x4f::look_at_rh(Vec3f::zero(), Vec3f::forward(), Vec3f::top());
+    /// assert_eq!(Vec4f::new(2., 0., 0., 1.) * m, Vec3f::new(0., 0., -2.));
+    /// ```
+    #[inline]
+    pub fn look_at_rh(eye: Vec3<F>, target: Vec3<F>, up: Vec3<F>) -> Self {
+        let f = (target - eye).normalized();
+        let s = f.cross(up).normalized();
+        let u = s.cross(f);
+        Self {
+            r0: Vec4::from_vec3(s, -s.dot(eye)),
+            r1: Vec4::from_vec3(u, -u.dot(eye)),
+            r2: Vec4::from_vec3(-f, f.dot(eye)),
+        }
+    }
+
+    /// Creates new left handed view matrix for the camera at `eye` looking at `target`.
+    /// In view space the camera looks towards `+Z` with `+Y` being up.
+    #[inline]
+    pub fn look_at_lh(eye: Vec3<F>, target: Vec3<F>, up: Vec3<F>) -> Self {
+        let f = (target - eye).normalized();
+        let s = up.cross(f).normalized();
+        let u = f.cross(s);
+        Self {
+            r0: Vec4::from_vec3(s, -s.dot(eye)),
+            r1: Vec4::from_vec3(u, -u.dot(eye)),
+            r2: Vec4::from_vec3(f, -f.dot(eye)),
+        }
+    }
+}
+
+impl<F> Add for Mat3x4<F>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            r0: self.r0 + rhs.r0,
+            r1: self.r1 + rhs.r1,
+            r2: self.r2 + rhs.r2,
+        }
+    }
+}
+
+impl<F> AddAssign for Mat3x4<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.r0 = self.r0 + rhs.r0;
+        self.r1 = self.r1 + rhs.r1;
+        self.r2 = self.r2 + rhs.r2;
+    }
+}
+
+impl<F> Sub for Mat3x4<F>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            r0: self.r0 - rhs.r0,
+            r1: self.r1 - rhs.r1,
+            r2: self.r2 - rhs.r2,
+        }
+    }
+}
+
+impl<F> SubAssign for Mat3x4<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.r0 = self.r0 - rhs.r0;
+        self.r1 = self.r1 - rhs.r1;
+        self.r2 = self.r2 - rhs.r2;
+    }
+}
+
+impl<F> Mul<F> for Mat3x4<F>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: F) -> Self::Output {
+        Self {
+            r0: self.r0 * rhs,
+            r1: self.r1 * rhs,
+            r2: self.r2 * rhs,
+        }
+    }
+}
+
+impl<F> MulAssign<F> for Mat3x4<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn mul_assign(&mut self, rhs: F) {
+        self.r0 = self.r0 * rhs;
+        self.r1 = self.r1 * rhs;
+        self.r2 = self.r2 * rhs;
+    }
+}
+
+impl<F> Div<F> for Mat3x4<F>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: F) -> Self::Output {
+        Self {
+            r0: self.r0 / rhs,
+            r1: self.r1 / rhs,
+            r2: self.r2 / rhs,
+        }
+    }
+}
+
+impl<F> DivAssign<F> for Mat3x4<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn div_assign(&mut self, rhs: F) {
+        self.r0 = self.r0 / rhs;
+        self.r1 = self.r1 / rhs;
+        self.r2 = self.r2 / rhs;
+    }
+}
+
+impl<F> Neg for Mat3x4<F>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self {
+            r0: -self.r0,
+            r1: -self.r1,
+            r2: -self.r2,
+        }
+    }
 }
 
 impl<F> Mul<Mat3x4<F>> for Vec4<F>
 where
-    F: Float,
+    F: Scalar,
 {
     type Output = Vec3<F>;
 
@@ -122,3 +474,100 @@ where
         }
     }
 }
+
+impl<F> Mul for Mat3x4<F>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    /// Composes two affine transformations, applying `rhs` first and then `self`.
+    /// Both matrices are treated as having `(0, 0, 0, 1)` bottom row.
+    /// ```
+    /// # use ewq::{mat::Mat3x4f, vec::{Vec3f, Vec4f}, Quatf};
+    /// let a = Mat3x4f::from_trs(Vec3f::new(1., 0., 0.), Quatf::identity(), Vec3f::one());
+    /// let b = Mat3x4f::from_trs(Vec3f::zero(), Quatf::identity(), Vec3f::new(2., 2., 2.));
+    /// assert_eq!(Vec4f::new(1., 1., 1., 1.) * (a * b), Vec3f::new(3., 2., 2.));
+    /// ```
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        let t = Mat4::from(rhs).transposed();
+        Self {
+            r0: t * self.r0,
+            r1: t * self.r1,
+            r2: t * self.r2,
+        }
+    }
+}
+
+impl<F> Index<(usize, usize)> for Mat3x4<F>
+where
+    F: Scalar,
+{
+    type Output = F;
+
+    /// Gets the element by `(row, column)` index.
+    /// # Panics
+    /// If index is out of range.
+    #[inline]
+    fn index(&self, (row, column): (usize, usize)) -> &Self::Output {
+        let row = match row {
+            0 => &self.r0,
+            1 => &self.r1,
+            2 => &self.r2,
+            _ => panic!("Index out of range"),
+        };
+        match column {
+            0 => &row.x,
+            1 => &row.y,
+            2 => &row.z,
+            3 => &row.w,
+            _ => panic!("Index out of range"),
+        }
+    }
+}
+
+impl<F> IndexMut<(usize, usize)> for Mat3x4<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut Self::Output {
+        let row = match row {
+            0 => &mut self.r0,
+            1 => &mut self.r1,
+            2 => &mut self.r2,
+            _ => panic!("Index out of range"),
+        };
+        match column {
+            0 => &mut row.x,
+            1 => &mut row.y,
+            2 => &mut row.z,
+            3 => &mut row.w,
+            _ => panic!("Index out of range"),
+        }
+    }
+}
+
+impl<F> Display for Mat3x4<F>
+where
+    F: Scalar + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        super::fmt_grid(f, 3, 4, |i, j| self[(i, j)])
+    }
+}
+
+/// Generates constants for the floating point matrix types.
+macro_rules! mat3x4_consts {
+    ($($f:ty),+) => {$(
+        impl Mat3x4<$f> {
+            /// Matrix with all elements set to `0`.
+            pub const ZERO: Self = Self::new(Vec4::<$f>::ZERO, Vec4::<$f>::ZERO, Vec4::<$f>::ZERO);
+            /// Identity transformation.
+            pub const IDENTITY: Self = Self::new(Vec4::<$f>::X, Vec4::<$f>::Y, Vec4::<$f>::Z);
+        }
+    )+};
+}
+
+mat3x4_consts!(f32, f64);
@@ -0,0 +1,935 @@
+use crate::vec::{Vec3, Vec4};
+use crate::Quat;
+use crate::plane::Plane3;
+use super::{Mat3, Mat3x4, Matrix};
+use crate::{Real, Scalar};
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+
+pub type Mat4f = Mat4<f32>;
+pub type Mat4d = Mat4<f64>;
+
+/// 4x4 matrix stored as four rows.
+#[derive(Debug, PartialEq, Default, Clone, Copy)]
+#[repr(C)]
+pub struct Mat4<F>
+where
+    F: Scalar,
+{
+    r0: Vec4<F>,
+    r1: Vec4<F>,
+    r2: Vec4<F>,
+    r3: Vec4<F>,
+}
+
+impl<F> Mat4<F>
+where
+    F: Scalar,
+{
+    /// Creates new matrix from rows.
+    #[inline]
+    pub const fn new(r0: Vec4<F>, r1: Vec4<F>, r2: Vec4<F>, r3: Vec4<F>) -> Self {
+        Self { r0, r1, r2, r3 }
+    }
+
+    /// Creates new matrix from columns.
+    #[inline]
+    pub fn from_columns(c0: Vec4<F>, c1: Vec4<F>, c2: Vec4<F>, c3: Vec4<F>) -> Self {
+        Self {
+            r0: Vec4::new(c0.x, c1.x, c2.x, c3.x),
+            r1: Vec4::new(c0.y, c1.y, c2.y, c3.y),
+            r2: Vec4::new(c0.z, c1.z, c2.z, c3.z),
+            r3: Vec4::new(c0.w, c1.w, c2.w, c3.w),
+        }
+    }
+
+    /// Gets matrix's row by index.
+    /// # Panics
+    /// If index is `>3`.
+    #[inline]
+    pub fn row<const I: usize>(&self) -> Vec4<F> {
+        self.row_at(I)
+    }
+
+    /// Sets matrix's row by index.
+    /// # Panics
+    /// If index is `>3`.
+    #[inline]
+    pub fn set_row<const I: usize>(&mut self, row: Vec4<F>) {
+        self.set_row_at(I, row)
+    }
+
+    /// Gets matrix's column by index.
+    /// # Panics
+    /// If index is `>3`.
+    #[inline]
+    pub fn column<const I: usize>(&self) -> Vec4<F> {
+        self.column_at(I)
+    }
+
+    /// Sets matrix's column by index.
+    /// # Panics
+    /// If index is `>3`.
+    #[inline]
+    pub fn set_column<const I: usize>(&mut self, column: Vec4<F>) {
+        self.set_column_at(I, column)
+    }
+
+    /// Gets matrix's row by runtime index.
+    /// # Panics
+    /// If index is `>3`.
+    #[inline]
+    pub fn row_at(&self, i: usize) -> Vec4<F> {
+        match i {
+            0 => self.r0,
+            1 => self.r1,
+            2 => self.r2,
+            3 => self.r3,
+            _ => panic!("Index out of range"),
+        }
+    }
+
+    /// Sets matrix's row by runtime index.
+    /// # Panics
+    /// If index is `>3`.
+    #[inline]
+    pub fn set_row_at(&mut self, i: usize, row: Vec4<F>) {
+        match i {
+            0 => self.r0 = row,
+            1 => self.r1 = row,
+            2 => self.r2 = row,
+            3 => self.r3 = row,
+            _ => panic!("Index out of range"),
+        }
+    }
+
+    /// Gets matrix's column by runtime index.
+    /// # Panics
+    /// If index is `>3`.
+    #[inline]
+    pub fn column_at(&self, i: usize) -> Vec4<F> {
+        match i {
+            0 => Vec4::new(self.r0.x, self.r1.x, self.r2.x, self.r3.x),
+            1 => Vec4::new(self.r0.y, self.r1.y, self.r2.y, self.r3.y),
+            2 => Vec4::new(self.r0.z, self.r1.z, self.r2.z, self.r3.z),
+            3 => Vec4::new(self.r0.w, self.r1.w, self.r2.w, self.r3.w),
+            _ => panic!("Index out of range"),
+        }
+    }
+
+    /// Sets matrix's column by runtime index.
+    /// # Panics
+    /// If index is `>3`.
+    #[inline]
+    pub fn set_column_at(&mut self, i: usize, column: Vec4<F>) {
+        match i {
+            0 => {
+                self.r0.x = column.x;
+                self.r1.x = column.y;
+                self.r2.x = column.z;
+                self.r3.x = column.w;
+            }
+            1 => {
+                self.r0.y = column.x;
+                self.r1.y = column.y;
+                self.r2.y = column.z;
+                self.r3.y = column.w;
+            }
+            2 => {
+                self.r0.z = column.x;
+                self.r1.z = column.y;
+                self.r2.z = column.z;
+                self.r3.z = column.w;
+            }
+            3 => {
+                self.r0.w = column.x;
+                self.r1.w = column.y;
+                self.r2.w = column.z;
+                self.r3.w = column.w;
+            }
+            _ => panic!("Index out of range"),
+        }
+    }
+
+    /// Creates new identity matrix.
+    /// For `f32` and `f64` the same value is available as the `IDENTITY` constant.
+    /// ```
+    /// # use ewq::{mat::Mat4d, Quatd};
+    /// const M: Mat4d = Mat4d::IDENTITY;
+    /// assert_eq!(M, Mat4d::identity());
+    /// assert_eq!(Quatd::IDENTITY, Quatd::identity());
+    /// ```
+    #[inline]
+    pub fn identity() -> Self {
+        Self {
+            r0: Vec4::new(F::one(), F::zero(), F::zero(), F::zero()),
+            r1: Vec4::new(F::zero(), F::one(), F::zero(), F::zero()),
+            r2: Vec4::new(F::zero(), F::zero(), F::one(), F::zero()),
+            r3: Vec4::new(F::zero(), F::zero(), F::zero(), F::one()),
+        }
+    }
+
+    /// Creates new matrix that scales vectors by `scale` along each axis.
+    #[inline]
+    pub fn from_scale(scale: Vec3<F>) -> Self {
+        Self {
+            r0: Vec4::new(scale.x, F::zero(), F::zero(), F::zero()),
+            r1: Vec4::new(F::zero(), scale.y, F::zero(), F::zero()),
+            r2: Vec4::new(F::zero(), F::zero(), scale.z, F::zero()),
+            r3: Vec4::new(F::zero(), F::zero(), F::zero(), F::one()),
+        }
+    }
+
+    /// Creates new matrix that translates points by `translation`.
+    /// ```
+    /// # use ewq::{mat::Mat4f, vec::{Vec3f, Vec4f}};
+    /// let m = Mat4f::from_translation(Vec3f::new(1., 2., 3.));
+    /// assert_eq!(m * Vec4f::new(1., 1., 1., 1.), Vec4f::new(2., 3., 4., 1.));
+    /// ```
+    #[inline]
+    pub fn from_translation(translation: Vec3<F>) -> Self {
+        Self {
+            r0: Vec4::new(F::one(), F::zero(), F::zero(), translation.x),
+            r1: Vec4::new(F::zero(), F::one(), F::zero(), translation.y),
+            r2: Vec4::new(F::zero(), F::zero(), F::one(), translation.z),
+            r3: Vec4::new(F::zero(), F::zero(), F::zero(), F::one()),
+        }
+    }
+
+    /// Creates new shear matrix where `ab` is the factor by which component `b` of the
+    /// vector is added to component `a`, e.g. `x' = x + xy * y + xz * z`.
+    /// ```
+    /// # use ewq::{mat::Mat4f, vec::Vec4f};
+    /// let m = Mat4f::from_shear(2., 0., 0., 0., 0., 0.);
+    /// assert_eq!(m * Vec4f::new(1., 1., 1., 1.), Vec4f::new(3., 1., 1., 1.));
+    /// ```
+    #[inline]
+    pub fn from_shear(xy: F, xz: F, yx: F, yz: F, zx: F, zy: F) -> Self {
+        Self {
+            r0: Vec4::new(F::one(), xy, xz, F::zero()),
+            r1: Vec4::new(yx, F::one(), yz, F::zero()),
+            r2: Vec4::new(zx, zy, F::one(), F::zero()),
+            r3: Vec4::new(F::zero(), F::zero(), F::zero(), F::one()),
+        }
+    }
+
+    /// Transposes the matrix in place.
+    #[inline]
+    pub fn transpose(&mut self) {
+        *self = self.transposed();
+    }
+
+    /// Returns the transposed copy of the matrix.
+    /// ```
+    /// # use ewq::{mat::Mat4f, vec::Vec4f};
+    /// let m = Mat4f::new(
+    ///     Vec4f::new(1., 2., 3., 4.),
+    ///     Vec4f::new(5., 6., 7., 8.),
+    ///     Vec4f::new(9., 10., 11., 12.),
+    ///     Vec4f::new(13., 14., 15., 16.),
+    /// );
+    /// assert_eq!(m.transposed().row::<0>(), Vec4f::new(1., 5., 9., 13.));
+    /// assert_eq!(m.transposed().transposed(), m);
+    /// ```
+    #[inline]
+    pub fn transposed(&self) -> Self {
+        Matrix::from(*self).transposed().into()
+    }
+
+    /// Computes the trace of the matrix, the sum of its diagonal elements.
+    #[inline]
+    pub fn trace(&self) -> F {
+        Matrix::from(*self).trace()
+    }
+
+    /// Computes the determinant of the matrix.
+    /// ```
+    /// # use ewq::mat::Mat4f;
+    /// assert_eq!(Mat4f::identity().determinant(), 1.);
+    /// ```
+    #[inline]
+    pub fn determinant(&self) -> F {
+        let (upper, lower) = self.minors();
+        Self::determinant_from_minors(upper, lower)
+    }
+
+    /// Computes the inverse of the matrix.
+    /// Returns `None` if the matrix is singular.
+    /// ```
+    /// # use ewq::{mat::Mat4f, vec::Vec4f};
+    /// let m = Mat4f::new(
+    ///     Vec4f::new(2., 0., 0., 1.),
+    ///     Vec4f::new(0., 0., 1., 2.),
+    ///     Vec4f::new(0., 4., 0., 3.),
+    ///     Vec4f::new(0., 0., 0., 1.),
+    /// );
+    /// assert_eq!(m * m.inverse().unwrap(), Mat4f::identity());
+    /// ```
+    pub fn inverse(&self) -> Option<Self> {
+        let (a, b, c, d) = (self.r0, self.r1, self.r2, self.r3);
+        let (upper, lower) = self.minors();
+
+        let det = Self::determinant_from_minors(upper, lower);
+        if det == F::zero() {
+            return None;
+        }
+
+        let [s0, s1, s2, s3, s4, s5] = upper;
+        let [c0, c1, c2, c3, c4, c5] = lower;
+
+        let inv = Self {
+            r0: Vec4::new(
+                b.y * c5 - b.z * c4 + b.w * c3,
+                -a.y * c5 + a.z * c4 - a.w * c3,
+                d.y * s5 - d.z * s4 + d.w * s3,
+                -c.y * s5 + c.z * s4 - c.w * s3,
+            ),
+            r1: Vec4::new(
+                -b.x * c5 + b.z * c2 - b.w * c1,
+                a.x * c5 - a.z * c2 + a.w * c1,
+                -d.x * s5 + d.z * s2 - d.w * s1,
+                c.x * s5 - c.z * s2 + c.w * s1,
+            ),
+            r2: Vec4::new(
+                b.x * c4 - b.y * c2 + b.w * c0,
+                -a.x * c4 + a.y * c2 - a.w * c0,
+                d.x * s4 - d.y * s2 + d.w * s0,
+                -c.x * s4 + c.y * s2 - c.w * s0,
+            ),
+            r3: Vec4::new(
+                -b.x * c3 + b.y * c1 - b.z * c0,
+                a.x * c3 - a.y * c1 + a.z * c0,
+                -d.x * s3 + d.y * s1 - d.z * s0,
+                c.x * s3 - c.y * s1 + c.z * s0,
+            ),
+        };
+
+        Some(Self {
+            r0: inv.r0 / det,
+            r1: inv.r1 / det,
+            r2: inv.r2 / det,
+            r3: inv.r3 / det,
+        })
+    }
+
+    /// Computes 2x2 minors of the upper and lower row pairs.
+    #[inline]
+    fn minors(&self) -> ([F; 6], [F; 6]) {
+        let (a, b, c, d) = (self.r0, self.r1, self.r2, self.r3);
+        (
+            [
+                a.x * b.y - b.x * a.y,
+                a.x * b.z - b.x * a.z,
+                a.x * b.w - b.x * a.w,
+                a.y * b.z - b.y * a.z,
+                a.y * b.w - b.y * a.w,
+                a.z * b.w - b.z * a.w,
+            ],
+            [
+                c.x * d.y - d.x * c.y,
+                c.x * d.z - d.x * c.z,
+                c.x * d.w - d.x * c.w,
+                c.y * d.z - d.y * c.z,
+                c.y * d.w - d.y * c.w,
+                c.z * d.w - d.z * c.w,
+            ],
+        )
+    }
+
+    #[inline]
+    fn determinant_from_minors(s: [F; 6], c: [F; 6]) -> F {
+        s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+    }
+
+    /// Computes the matrix for transforming normals.
+    /// See [`Mat3x4::normal_matrix`].
+    #[inline]
+    pub fn normal_matrix(&self) -> Option<Mat3<F>> {
+        Mat3x4::new(self.r0, self.r1, self.r2).normal_matrix()
+    }
+}
+
+impl<F> Mat4<F>
+where
+    F: Real,
+{
+    /// Converts the matrix to the other floating point precision.
+    /// ```
+    /// # use ewq::mat::{Mat4d, Mat4f};
+    /// assert_eq!(Mat4d::identity().cast::<f32>(), Mat4f::identity());
+    /// ```
+    #[inline]
+    pub fn cast<T>(&self) -> Mat4<T>
+    where
+        T: Real,
+    {
+        Mat4::new(
+            self.r0.cast(),
+            self.r1.cast(),
+            self.r2.cast(),
+            self.r3.cast(),
+        )
+    }
+
+    /// Checks if all elements of two matrices differ by no more than `epsilon`.
+    /// ```
+    /// # use ewq::{mat::Mat4d, vec::Vec4d};
+    /// let m = Mat4d::new(
+    ///     Vec4d::new(1., 2., 0., 0.),
+    ///     Vec4d::new(3., 4., 0., 0.),
+    ///     Vec4d::new(0., 0., 5., 6.),
+    ///     Vec4d::new(0., 0., 7., 8.),
+    /// );
+    /// assert!((m * m.inverse().unwrap()).abs_diff_eq(&Mat4d::identity(), 1e-12));
+    /// ```
+    #[inline]
+    pub fn abs_diff_eq(&self, other: &Self, epsilon: F) -> bool {
+        Matrix::from(*self).abs_diff_eq(&Matrix::from(*other), epsilon)
+    }
+
+    /// Checks if all elements of two matrices are equal within `epsilon`
+    /// or within `max_relative` of the larger element magnitude.
+    #[inline]
+    pub fn relative_eq(&self, other: &Self, epsilon: F, max_relative: F) -> bool {
+        Matrix::from(*self).relative_eq(&Matrix::from(*other), epsilon, max_relative)
+    }
+
+    /// Creates new matrix that scales, then rotates and then translates vectors.
+    /// See [`Mat3x4::from_trs`].
+    #[inline]
+    pub fn from_trs(translation: Vec3<F>, rotation: Quat<F>, scale: Vec3<F>) -> Self {
+        Mat3x4::from_trs(translation, rotation, scale).into()
+    }
+
+    /// Decomposes affine matrix into translation, rotation and scale.
+    /// The last row is ignored. See [`Mat3x4::decompose`].
+    #[inline]
+    pub fn decompose(&self) -> (Vec3<F>, Quat<F>, Vec3<F>) {
+        Mat3x4::new(self.r0, self.r1, self.r2).decompose()
+    }
+
+    /// Creates new right handed view matrix.
+    /// Same as [`Mat4::look_at_rh`].
+    #[inline]
+    pub fn look_at(eye: Vec3<F>, target: Vec3<F>, up: Vec3<F>) -> Self {
+        Self::look_at_rh(eye, target, up)
+    }
+
+    /// Creates new right handed view matrix for the camera at `eye` looking at `target`.
+    /// See [`Mat3x4::look_at_rh`].
+    #[inline]
+    pub fn look_at_rh(eye: Vec3<F>, target: Vec3<F>, up: Vec3<F>) -> Self {
+        Mat3x4::look_at_rh(eye, target, up).into()
+    }
+
+    /// Creates new left handed view matrix for the camera at `eye` looking at `target`.
+    /// See [`Mat3x4::look_at_lh`].
+    #[inline]
+    pub fn look_at_lh(eye: Vec3<F>, target: Vec3<F>, up: Vec3<F>) -> Self {
+        Mat3x4::look_at_lh(eye, target, up).into()
+    }
+
+    /// Creates new matrix that represents a rotation around `X` axis by `angle` radians.
+    #[inline]
+    pub fn rotation_x(angle: F) -> Self {
+        Mat3::rotation_x(angle).into()
+    }
+
+    /// Creates new matrix that represents a rotation around `Y` axis by `angle` radians.
+    #[inline]
+    pub fn rotation_y(angle: F) -> Self {
+        Mat3::rotation_y(angle).into()
+    }
+
+    /// Creates new matrix that represents a rotation around `Z` axis by `angle` radians.
+    #[inline]
+    pub fn rotation_z(angle: F) -> Self {
+        Mat3::rotation_z(angle).into()
+    }
+
+    /// Creates new right handed perspective projection matrix.
+    /// Same as [`Mat4::perspective_rh`].
+    #[inline]
+    pub fn perspective(fov_y: F, aspect: F, near: F, far: F) -> Self {
+        Self::perspective_rh(fov_y, aspect, near, far)
+    }
+
+    /// Creates new right handed perspective projection matrix with vertical field of view
+    /// `fov_y` in radians. View space looks towards `-Z` and depth is mapped to `[0, 1]`.
+    /// ```
+    /// # use ewq::{mat::Mat4d, vec::Vec4d};
+    /// let m = Mat4d::perspective_rh(std::f64::consts::FRAC_PI_2, 1., 1., 10.);
+    /// let near = m * Vec4d::new(0., 0., -1., 1.);
+    /// let far = m * Vec4d::new(0., 0., -10., 1.);
+    /// assert_eq!(near.z / near.w, 0.);
+    /// assert_eq!(far.z / far.w, 1.);
+    /// ```
+    #[inline]
+    pub fn perspective_rh(fov_y: F, aspect: F, near: F, far: F) -> Self {
+        let f = F::one() / (fov_y / F::from(2).unwrap()).tan();
+        let range = near - far;
+        Self {
+            r0: Vec4::new(f / aspect, F::zero(), F::zero(), F::zero()),
+            r1: Vec4::new(F::zero(), f, F::zero(), F::zero()),
+            r2: Vec4::new(F::zero(), F::zero(), far / range, near * far / range),
+            r3: Vec4::new(F::zero(), F::zero(), -F::one(), F::zero()),
+        }
+    }
+
+    /// Creates new left handed perspective projection matrix with vertical field of view
+    /// `fov_y` in radians. View space looks towards `+Z` and depth is mapped to `[0, 1]`.
+    #[inline]
+    pub fn perspective_lh(fov_y: F, aspect: F, near: F, far: F) -> Self {
+        let f = F::one() / (fov_y / F::from(2).unwrap()).tan();
+        let range = far - near;
+        Self {
+            r0: Vec4::new(f / aspect, F::zero(), F::zero(), F::zero()),
+            r1: Vec4::new(F::zero(), f, F::zero(), F::zero()),
+            r2: Vec4::new(F::zero(), F::zero(), far / range, -near * far / range),
+            r3: Vec4::new(F::zero(), F::zero(), F::one(), F::zero()),
+        }
+    }
+
+    /// Creates new right handed perspective projection matrix with reversed depth,
+    /// mapping `near` to `1` and `far` to `0`. See [`Mat4::perspective_rh`].
+    /// ```
+    /// # use ewq::{mat::Mat4d, vec::Vec4d};
+    /// let m = Mat4d::perspective_reversed_z(std::f64::consts::FRAC_PI_2, 1., 1., 10.);
+    /// let near = m * Vec4d::new(0., 0., -1., 1.);
+    /// let far = m * Vec4d::new(0., 0., -10., 1.);
+    /// assert_eq!(near.z / near.w, 1.);
+    /// assert_eq!(far.z / far.w, 0.);
+    /// ```
+    #[inline]
+    pub fn perspective_reversed_z(fov_y: F, aspect: F, near: F, far: F) -> Self {
+        let f = F::one() / (fov_y / F::from(2).unwrap()).tan();
+        let range = far - near;
+        Self {
+            r0: Vec4::new(f / aspect, F::zero(), F::zero(), F::zero()),
+            r1: Vec4::new(F::zero(), f, F::zero(), F::zero()),
+            r2: Vec4::new(F::zero(), F::zero(), near / range, near * far / range),
+            r3: Vec4::new(F::zero(), F::zero(), -F::one(), F::zero()),
+        }
+    }
+
+    /// Creates new right handed perspective projection matrix with the far plane at infinity,
+    /// mapping `near` to `0`. See [`Mat4::perspective_rh`].
+    #[inline]
+    pub fn perspective_infinite(fov_y: F, aspect: F, near: F) -> Self {
+        let f = F::one() / (fov_y / F::from(2).unwrap()).tan();
+        Self {
+            r0: Vec4::new(f / aspect, F::zero(), F::zero(), F::zero()),
+            r1: Vec4::new(F::zero(), f, F::zero(), F::zero()),
+            r2: Vec4::new(F::zero(), F::zero(), -F::one(), -near),
+            r3: Vec4::new(F::zero(), F::zero(), -F::one(), F::zero()),
+        }
+    }
+
+    /// Creates new right handed perspective projection matrix with the far plane at infinity
+    /// and reversed depth, mapping `near` to `1`. See [`Mat4::perspective_rh`].
+    /// ```
+    /// # use ewq::{mat::Mat4d, vec::Vec4d};
+    /// let m = Mat4d::perspective_infinite_reversed_z(std::f64::consts::FRAC_PI_2, 1., 1.);
+    /// let near = m * Vec4d::new(0., 0., -1., 1.);
+    /// assert_eq!(near.z / near.w, 1.);
+    /// ```
+    #[inline]
+    pub fn perspective_infinite_reversed_z(fov_y: F, aspect: F, near: F) -> Self {
+        let f = F::one() / (fov_y / F::from(2).unwrap()).tan();
+        Self {
+            r0: Vec4::new(f / aspect, F::zero(), F::zero(), F::zero()),
+            r1: Vec4::new(F::zero(), f, F::zero(), F::zero()),
+            r2: Vec4::new(F::zero(), F::zero(), F::zero(), near),
+            r3: Vec4::new(F::zero(), F::zero(), -F::one(), F::zero()),
+        }
+    }
+
+    /// Creates new right handed orthographic projection matrix.
+    /// Same as [`Mat4::orthographic_rh`].
+    #[inline]
+    pub fn orthographic(left: F, right: F, bottom: F, top: F, near: F, far: F) -> Self {
+        Self::orthographic_rh(left, right, bottom, top, near, far)
+    }
+
+    /// Creates new right handed orthographic projection matrix.
+    /// View space looks towards `-Z` and depth is mapped to `[0, 1]`.
+    /// ```
+    /// # use ewq::{mat::Mat4f, vec::Vec4f};
+    /// let m = Mat4f::orthographic_rh(0., 800., 0., 600., 1., 11.);
+    /// assert_eq!(m * Vec4f::new(0., 0., -1., 1.), Vec4f::new(-1., -1., 0., 1.));
+    /// assert_eq!(m * Vec4f::new(800., 600., -11., 1.), Vec4f::new(1., 1., 1., 1.));
+    /// ```
+    #[inline]
+    pub fn orthographic_rh(left: F, right: F, bottom: F, top: F, near: F, far: F) -> Self {
+        let two = F::from(2).unwrap();
+        let (width, height, depth) = (right - left, top - bottom, near - far);
+        Self {
+            r0: Vec4::new(two / width, F::zero(), F::zero(), -(right + left) / width),
+            r1: Vec4::new(F::zero(), two / height, F::zero(), -(top + bottom) / height),
+            r2: Vec4::new(F::zero(), F::zero(), F::one() / depth, near / depth),
+            r3: Vec4::new(F::zero(), F::zero(), F::zero(), F::one()),
+        }
+    }
+
+    /// Creates new left handed orthographic projection matrix.
+    /// View space looks towards `+Z` and depth is mapped to `[0, 1]`.
+    #[inline]
+    pub fn orthographic_lh(left: F, right: F, bottom: F, top: F, near: F, far: F) -> Self {
+        let two = F::from(2).unwrap();
+        let (width, height, depth) = (right - left, top - bottom, far - near);
+        Self {
+            r0: Vec4::new(two / width, F::zero(), F::zero(), -(right + left) / width),
+            r1: Vec4::new(F::zero(), two / height, F::zero(), -(top + bottom) / height),
+            r2: Vec4::new(F::zero(), F::zero(), F::one() / depth, -near / depth),
+            r3: Vec4::new(F::zero(), F::zero(), F::zero(), F::one()),
+        }
+    }
+
+    /// Computes the Frobenius norm of the matrix.
+    #[inline]
+    pub fn frobenius_norm(&self) -> F {
+        Matrix::from(*self).frobenius_norm()
+    }
+
+    /// Extracts normalized clip planes from the view-projection matrix in order:
+    /// left, right, bottom, top, near, far. Normals point inside the frustum.
+    /// Depth is expected to be mapped to `[0, 1]`.
+    /// ```
+    /// # use ewq::{mat::Mat4f, vec::Vec3f};
+    /// let m = Mat4f::orthographic(-1., 1., -1., 1., 1., 10.);
+    /// let [left, _, _, _, near, far] = m.frustum_planes();
+    /// assert_eq!(left.signed_distance(Vec3f::new(0., 0., -5.)), 1.);
+    /// assert_eq!(near.signed_distance(Vec3f::new(0., 0., -5.)), 4.);
+    /// assert_eq!(far.signed_distance(Vec3f::new(0., 0., -5.)), 5.);
+    /// ```
+    pub fn frustum_planes(&self) -> [Plane3<F>; 6] {
+        [
+            self.r3 + self.r0,
+            self.r3 - self.r0,
+            self.r3 + self.r1,
+            self.r3 - self.r1,
+            self.r2,
+            self.r3 - self.r2,
+        ]
+        .map(|v| Plane3::from_vec4(v).normalized())
+    }
+
+    /// Solves linear system `self * x = b` for `x`.
+    /// Returns `None` if the matrix is singular. See [`Matrix::solve`].
+    #[inline]
+    pub fn solve(&self, b: Vec4<F>) -> Option<Vec4<F>> {
+        let [x, y, z, w] = Matrix::from(*self).solve([b.x, b.y, b.z, b.w])?;
+        Some(Vec4::new(x, y, z, w))
+    }
+}
+
+impl<F> Add for Mat4<F>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            r0: self.r0 + rhs.r0,
+            r1: self.r1 + rhs.r1,
+            r2: self.r2 + rhs.r2,
+            r3: self.r3 + rhs.r3,
+        }
+    }
+}
+
+impl<F> AddAssign for Mat4<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.r0 = self.r0 + rhs.r0;
+        self.r1 = self.r1 + rhs.r1;
+        self.r2 = self.r2 + rhs.r2;
+        self.r3 = self.r3 + rhs.r3;
+    }
+}
+
+impl<F> Sub for Mat4<F>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            r0: self.r0 - rhs.r0,
+            r1: self.r1 - rhs.r1,
+            r2: self.r2 - rhs.r2,
+            r3: self.r3 - rhs.r3,
+        }
+    }
+}
+
+impl<F> SubAssign for Mat4<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.r0 = self.r0 - rhs.r0;
+        self.r1 = self.r1 - rhs.r1;
+        self.r2 = self.r2 - rhs.r2;
+        self.r3 = self.r3 - rhs.r3;
+    }
+}
+
+impl<F> Mul<F> for Mat4<F>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: F) -> Self::Output {
+        Self {
+            r0: self.r0 * rhs,
+            r1: self.r1 * rhs,
+            r2: self.r2 * rhs,
+            r3: self.r3 * rhs,
+        }
+    }
+}
+
+impl<F> MulAssign<F> for Mat4<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn mul_assign(&mut self, rhs: F) {
+        self.r0 = self.r0 * rhs;
+        self.r1 = self.r1 * rhs;
+        self.r2 = self.r2 * rhs;
+        self.r3 = self.r3 * rhs;
+    }
+}
+
+impl<F> Div<F> for Mat4<F>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: F) -> Self::Output {
+        Self {
+            r0: self.r0 / rhs,
+            r1: self.r1 / rhs,
+            r2: self.r2 / rhs,
+            r3: self.r3 / rhs,
+        }
+    }
+}
+
+impl<F> DivAssign<F> for Mat4<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn div_assign(&mut self, rhs: F) {
+        self.r0 = self.r0 / rhs;
+        self.r1 = self.r1 / rhs;
+        self.r2 = self.r2 / rhs;
+        self.r3 = self.r3 / rhs;
+    }
+}
+
+impl<F> Neg for Mat4<F>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self {
+            r0: -self.r0,
+            r1: -self.r1,
+            r2: -self.r2,
+            r3: -self.r3,
+        }
+    }
+}
+
+impl<F> Mul<Vec4<F>> for Mat4<F>
+where
+    F: Scalar,
+{
+    type Output = Vec4<F>;
+
+    /// Transforms the vector by the matrix.
+    /// ```
+    /// # use ewq::{mat::Mat4f, vec::Vec4f};
+    /// let v = Vec4f::new(1., 2., 3., 4.);
+    /// assert_eq!(Mat4f::identity() * v, v);
+    /// ```
+    #[inline]
+    fn mul(self, rhs: Vec4<F>) -> Self::Output {
+        Vec4 {
+            x: rhs.dot(self.r0),
+            y: rhs.dot(self.r1),
+            z: rhs.dot(self.r2),
+            w: rhs.dot(self.r3),
+        }
+    }
+}
+
+impl<F> Mul<Mat4<F>> for Vec4<F>
+where
+    F: Scalar,
+{
+    type Output = Vec4<F>;
+
+    #[inline]
+    fn mul(self, rhs: Mat4<F>) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl<F> From<Mat3<F>> for Mat4<F>
+where
+    F: Scalar,
+{
+    /// Expands matrix with zero translation and `(0, 0, 0, 1)` row.
+    #[inline]
+    fn from(m: Mat3<F>) -> Self {
+        Mat3x4::from(m).into()
+    }
+}
+
+impl<F> From<Mat3x4<F>> for Mat4<F>
+where
+    F: Scalar,
+{
+    /// Expands affine matrix with `(0, 0, 0, 1)` row.
+    #[inline]
+    fn from(m: Mat3x4<F>) -> Self {
+        Self {
+            r0: m.row::<0>(),
+            r1: m.row::<1>(),
+            r2: m.row::<2>(),
+            r3: Vec4::new(F::zero(), F::zero(), F::zero(), F::one()),
+        }
+    }
+}
+
+impl<F> Mul for Mat4<F>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    /// Composes two transformations, applying `rhs` first and then `self`.
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        (Matrix::from(self) * Matrix::from(rhs)).into()
+    }
+}
+
+impl<F> Mul<Mat3x4<F>> for Mat4<F>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    /// Composes with the affine matrix, treating it as having `(0, 0, 0, 1)` bottom row.
+    #[inline]
+    fn mul(self, rhs: Mat3x4<F>) -> Self::Output {
+        self * Mat4::from(rhs)
+    }
+}
+
+impl<F> Mul<Mat4<F>> for Mat3x4<F>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Mat4<F>) -> Self::Output {
+        (Matrix::from(self) * Matrix::from(rhs)).into()
+    }
+}
+
+impl<F> Index<(usize, usize)> for Mat4<F>
+where
+    F: Scalar,
+{
+    type Output = F;
+
+    /// Gets the element by `(row, column)` index.
+    /// # Panics
+    /// If index is out of range.
+    #[inline]
+    fn index(&self, (row, column): (usize, usize)) -> &Self::Output {
+        let row = match row {
+            0 => &self.r0,
+            1 => &self.r1,
+            2 => &self.r2,
+            3 => &self.r3,
+            _ => panic!("Index out of range"),
+        };
+        match column {
+            0 => &row.x,
+            1 => &row.y,
+            2 => &row.z,
+            3 => &row.w,
+            _ => panic!("Index out of range"),
+        }
+    }
+}
+
+impl<F> IndexMut<(usize, usize)> for Mat4<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut Self::Output {
+        let row = match row {
+            0 => &mut self.r0,
+            1 => &mut self.r1,
+            2 => &mut self.r2,
+            3 => &mut self.r3,
+            _ => panic!("Index out of range"),
+        };
+        match column {
+            0 => &mut row.x,
+            1 => &mut row.y,
+            2 => &mut row.z,
+            3 => &mut row.w,
+            _ => panic!("Index out of range"),
+        }
+    }
+}
+
+impl<F> Display for Mat4<F>
+where
+    F: Scalar + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        super::fmt_grid(f, 4, 4, |i, j| self[(i, j)])
+    }
+}
+
+/// Generates constants for the floating point matrix types.
+macro_rules! mat4_consts {
+    ($($f:ty),+) => {$(
+        impl Mat4<$f> {
+            /// Matrix with all elements set to `0`.
+            pub const ZERO: Self = Self::new(Vec4::<$f>::ZERO, Vec4::<$f>::ZERO, Vec4::<$f>::ZERO, Vec4::<$f>::ZERO);
+            /// Identity matrix.
+            pub const IDENTITY: Self = Self::new(Vec4::<$f>::X, Vec4::<$f>::Y, Vec4::<$f>::Z, Vec4::<$f>::W);
+        }
+    )+};
+}
+
+mat4_consts!(f32, f64);
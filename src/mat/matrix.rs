@@ -0,0 +1,481 @@
+use super::{Mat2, Mat3, Mat3x4, Mat4};
+use crate::vec::{Vec2, Vec3, Vec4};
+use crate::{scalar::mul_add, scalar::relative_eq, Real, Scalar};
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// 4x3 matrix.
+pub type Mat4x3<F> = Matrix<F, 4, 3>;
+/// 2x3 matrix.
+pub type Mat2x3<F> = Matrix<F, 2, 3>;
+/// 3x2 matrix.
+pub type Mat3x2<F> = Matrix<F, 3, 2>;
+
+/// Generic matrix with `R` rows and `C` columns stored in row-major order.
+/// Fixed size matrix types can be converted to and from it, and delegate
+/// transposition, products, norms and approximate comparisons to it.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct Matrix<F, const R: usize, const C: usize>
+where
+    F: Scalar,
+{
+    m: [[F; C]; R],
+}
+
+impl<F, const R: usize, const C: usize> Matrix<F, R, C>
+where
+    F: Scalar,
+{
+    /// Creates new matrix from rows.
+    #[inline]
+    pub const fn new(rows: [[F; C]; R]) -> Self {
+        Self { m: rows }
+    }
+
+    /// Creates new matrix filled with zeros.
+    #[inline]
+    pub fn zero() -> Self {
+        Self {
+            m: [[F::zero(); C]; R],
+        }
+    }
+
+    /// Creates new matrix with ones on the main diagonal and zeros elsewhere.
+    #[inline]
+    pub fn identity() -> Self {
+        let mut m = Self::zero();
+        for i in 0..R.min(C) {
+            m.m[i][i] = F::one();
+        }
+        m
+    }
+
+    /// Returns the rows of the matrix.
+    #[inline]
+    pub fn rows(&self) -> [[F; C]; R] {
+        self.m
+    }
+
+    /// Gets the element at `row` and `column`.
+    /// # Panics
+    /// If `row >= R` or `column >= C`.
+    #[inline]
+    pub fn get(&self, row: usize, column: usize) -> F {
+        self.m[row][column]
+    }
+
+    /// Sets the element at `row` and `column`.
+    /// # Panics
+    /// If `row >= R` or `column >= C`.
+    #[inline]
+    pub fn set(&mut self, row: usize, column: usize, value: F) {
+        self.m[row][column] = value;
+    }
+
+    /// Returns the transposed copy of the matrix.
+    /// ```
+    /// # use ewq::mat::Matrix;
+    /// let m = Matrix::new([[1., 2., 3.], [4., 5., 6.]]);
+    /// assert_eq!(m.transposed(), Matrix::new([[1., 4.], [2., 5.], [3., 6.]]));
+    /// ```
+    #[inline]
+    pub fn transposed(&self) -> Matrix<F, C, R> {
+        let mut t = Matrix::zero();
+        for i in 0..R {
+            for j in 0..C {
+                t.m[j][i] = self.m[i][j];
+            }
+        }
+        t
+    }
+}
+
+impl<F, const R: usize, const C: usize> Matrix<F, R, C>
+where
+    F: Real,
+{
+    /// Checks if all elements of two matrices differ by no more than `epsilon`.
+    #[inline]
+    pub fn abs_diff_eq(&self, other: &Self, epsilon: F) -> bool {
+        self.m
+            .iter()
+            .flatten()
+            .zip(other.m.iter().flatten())
+            .all(|(a, b)| (*a - *b).abs() <= epsilon)
+    }
+
+    /// Checks if all elements of two matrices are equal within `epsilon`
+    /// or within `max_relative` of the larger element magnitude.
+    #[inline]
+    pub fn relative_eq(&self, other: &Self, epsilon: F, max_relative: F) -> bool {
+        self.m
+            .iter()
+            .flatten()
+            .zip(other.m.iter().flatten())
+            .all(|(a, b)| relative_eq(*a, *b, epsilon, max_relative))
+    }
+
+    /// Computes the Frobenius norm of the matrix.
+    #[inline]
+    pub fn frobenius_norm(&self) -> F {
+        F::sqrt(
+            self.m
+                .iter()
+                .flatten()
+                .fold(F::zero(), |acc, a| mul_add(*a, *a, acc)),
+        )
+    }
+}
+
+impl<F, const N: usize> Matrix<F, N, N>
+where
+    F: Scalar,
+{
+    /// Computes the trace of the matrix, the sum of its diagonal elements.
+    #[inline]
+    pub fn trace(&self) -> F {
+        (0..N).fold(F::zero(), |acc, i| acc + self.m[i][i])
+    }
+}
+
+impl<F, const N: usize> Matrix<F, N, N>
+where
+    F: Real,
+{
+    /// Solves linear system `self * x = b` using LU decomposition with partial pivoting.
+    /// Returns `None` if the matrix is singular.
+    /// ```
+    /// # use ewq::mat::Matrix;
+    /// let m = Matrix::new([[2., 1.], [1., 3.]]);
+    /// assert_eq!(m.solve([3., 5.]), Some([0.8, 1.4]));
+    /// ```
+    pub fn solve(&self, b: [F; N]) -> Option<[F; N]> {
+        let (mut lu, mut x) = (self.m, b);
+
+        for k in 0..N {
+            let p = (k..N).fold(k, |p, i| {
+                if lu[i][k].abs() > lu[p][k].abs() {
+                    i
+                } else {
+                    p
+                }
+            });
+            if lu[p][k] == F::zero() {
+                return None;
+            }
+            lu.swap(k, p);
+            x.swap(k, p);
+
+            let pivot = lu[k];
+            for i in k + 1..N {
+                let factor = lu[i][k] / pivot[k];
+                for (a, b) in lu[i][k..].iter_mut().zip(&pivot[k..]) {
+                    *a = *a - factor * *b;
+                }
+                x[i] = x[i] - factor * x[k];
+            }
+        }
+
+        for i in (0..N).rev() {
+            let sum = (i + 1..N).fold(x[i], |acc, j| acc - lu[i][j] * x[j]);
+            x[i] = sum / lu[i][i];
+        }
+        Some(x)
+    }
+}
+
+impl<F, const R: usize, const C: usize> Default for Matrix<F, R, C>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<F, const R: usize, const C: usize> Index<(usize, usize)> for Matrix<F, R, C>
+where
+    F: Scalar,
+{
+    type Output = F;
+
+    /// Gets the element by `(row, column)` index.
+    /// # Panics
+    /// If `row >= R` or `column >= C`.
+    #[inline]
+    fn index(&self, (row, column): (usize, usize)) -> &Self::Output {
+        &self.m[row][column]
+    }
+}
+
+impl<F, const R: usize, const C: usize> IndexMut<(usize, usize)> for Matrix<F, R, C>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut Self::Output {
+        &mut self.m[row][column]
+    }
+}
+
+impl<F, const R: usize, const C: usize> Add for Matrix<F, R, C>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self.add_assign(rhs);
+        self
+    }
+}
+
+impl<F, const R: usize, const C: usize> AddAssign for Matrix<F, R, C>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        for (a, b) in self.m.iter_mut().flatten().zip(rhs.m.iter().flatten()) {
+            *a = *a + *b;
+        }
+    }
+}
+
+impl<F, const R: usize, const C: usize> Sub for Matrix<F, R, C>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self.sub_assign(rhs);
+        self
+    }
+}
+
+impl<F, const R: usize, const C: usize> SubAssign for Matrix<F, R, C>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        for (a, b) in self.m.iter_mut().flatten().zip(rhs.m.iter().flatten()) {
+            *a = *a - *b;
+        }
+    }
+}
+
+impl<F, const R: usize, const C: usize> Mul<F> for Matrix<F, R, C>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    #[inline]
+    fn mul(mut self, rhs: F) -> Self::Output {
+        self.mul_assign(rhs);
+        self
+    }
+}
+
+impl<F, const R: usize, const C: usize> MulAssign<F> for Matrix<F, R, C>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn mul_assign(&mut self, rhs: F) {
+        for a in self.m.iter_mut().flatten() {
+            *a = *a * rhs;
+        }
+    }
+}
+
+impl<F, const R: usize, const C: usize> Div<F> for Matrix<F, R, C>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    #[inline]
+    fn div(mut self, rhs: F) -> Self::Output {
+        self.div_assign(rhs);
+        self
+    }
+}
+
+impl<F, const R: usize, const C: usize> DivAssign<F> for Matrix<F, R, C>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn div_assign(&mut self, rhs: F) {
+        for a in self.m.iter_mut().flatten() {
+            *a = *a / rhs;
+        }
+    }
+}
+
+impl<F, const R: usize, const C: usize> Neg for Matrix<F, R, C>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    #[inline]
+    fn neg(mut self) -> Self::Output {
+        for a in self.m.iter_mut().flatten() {
+            *a = -*a;
+        }
+        self
+    }
+}
+
+impl<F, const R: usize, const N: usize, const C: usize> Mul<Matrix<F, N, C>> for Matrix<F, R, N>
+where
+    F: Scalar,
+{
+    type Output = Matrix<F, R, C>;
+
+    /// Multiplies matrices of compatible dimensions.
+    /// ```
+    /// # use ewq::mat::Matrix;
+    /// let a = Matrix::new([[1., 2., 3.], [4., 5., 6.]]);
+    /// let b = Matrix::new([[1.], [0.], [1.]]);
+    /// assert_eq!(a * b, Matrix::new([[4.], [10.]]));
+    /// ```
+    #[inline]
+    fn mul(self, rhs: Matrix<F, N, C>) -> Self::Output {
+        let mut out = Matrix::zero();
+        for i in 0..R {
+            for j in 0..C {
+                out.m[i][j] =
+                    (0..N).fold(F::zero(), |acc, k| mul_add(self.m[i][k], rhs.m[k][j], acc));
+            }
+        }
+        out
+    }
+}
+
+impl<F> From<Mat2<F>> for Matrix<F, 2, 2>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn from(m: Mat2<F>) -> Self {
+        let (r0, r1) = (m.row::<0>(), m.row::<1>());
+        Self::new([[r0.x, r0.y], [r1.x, r1.y]])
+    }
+}
+
+impl<F> From<Matrix<F, 2, 2>> for Mat2<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn from(m: Matrix<F, 2, 2>) -> Self {
+        let [r0, r1] = m.m;
+        Self::new(Vec2::new(r0[0], r0[1]), Vec2::new(r1[0], r1[1]))
+    }
+}
+
+impl<F> From<Mat3<F>> for Matrix<F, 3, 3>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn from(m: Mat3<F>) -> Self {
+        let (r0, r1, r2) = (m.row::<0>(), m.row::<1>(), m.row::<2>());
+        Self::new([[r0.x, r0.y, r0.z], [r1.x, r1.y, r1.z], [r2.x, r2.y, r2.z]])
+    }
+}
+
+impl<F> From<Matrix<F, 3, 3>> for Mat3<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn from(m: Matrix<F, 3, 3>) -> Self {
+        let [r0, r1, r2] = m.m;
+        Self::new(
+            Vec3::new(r0[0], r0[1], r0[2]),
+            Vec3::new(r1[0], r1[1], r1[2]),
+            Vec3::new(r2[0], r2[1], r2[2]),
+        )
+    }
+}
+
+impl<F> From<Mat3x4<F>> for Matrix<F, 3, 4>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn from(m: Mat3x4<F>) -> Self {
+        let (r0, r1, r2) = (m.row::<0>(), m.row::<1>(), m.row::<2>());
+        Self::new([
+            [r0.x, r0.y, r0.z, r0.w],
+            [r1.x, r1.y, r1.z, r1.w],
+            [r2.x, r2.y, r2.z, r2.w],
+        ])
+    }
+}
+
+impl<F> From<Matrix<F, 3, 4>> for Mat3x4<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn from(m: Matrix<F, 3, 4>) -> Self {
+        let [r0, r1, r2] = m.m;
+        Self::new(
+            Vec4::new(r0[0], r0[1], r0[2], r0[3]),
+            Vec4::new(r1[0], r1[1], r1[2], r1[3]),
+            Vec4::new(r2[0], r2[1], r2[2], r2[3]),
+        )
+    }
+}
+
+impl<F> From<Mat4<F>> for Matrix<F, 4, 4>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn from(m: Mat4<F>) -> Self {
+        let (r0, r1, r2, r3) = (m.row::<0>(), m.row::<1>(), m.row::<2>(), m.row::<3>());
+        Self::new([
+            [r0.x, r0.y, r0.z, r0.w],
+            [r1.x, r1.y, r1.z, r1.w],
+            [r2.x, r2.y, r2.z, r2.w],
+            [r3.x, r3.y, r3.z, r3.w],
+        ])
+    }
+}
+
+impl<F> From<Matrix<F, 4, 4>> for Mat4<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn from(m: Matrix<F, 4, 4>) -> Self {
+        let [r0, r1, r2, r3] = m.m;
+        Self::new(
+            Vec4::new(r0[0], r0[1], r0[2], r0[3]),
+            Vec4::new(r1[0], r1[1], r1[2], r1[3]),
+            Vec4::new(r2[0], r2[1], r2[2], r2[3]),
+            Vec4::new(r3[0], r3[1], r3[2], r3[3]),
+        )
+    }
+}
+
+impl<F, const R: usize, const C: usize> Display for Matrix<F, R, C>
+where
+    F: Scalar + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        super::fmt_grid(f, R, C, |i, j| self.m[i][j])
+    }
+}
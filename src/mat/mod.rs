@@ -1,2 +1,51 @@
-mod mat3x4;
-pub use mat3x4::*;
+use std::fmt::{self, Display, Formatter};
+
+mod mat2;
+pub use mat2::*;
+
+mod mat3;
+pub use mat3::*;
+
+mod mat3x4;
+pub use mat3x4::*;
+
+mod mat4;
+pub use mat4::*;
+
+mod matrix;
+pub use matrix::*;
+
+/// Writes `rows` by `columns` grid of elements with right aligned columns.
+/// Respects the precision of the formatter.
+fn fmt_grid<F, E>(f: &mut Formatter<'_>, rows: usize, columns: usize, element: E) -> fmt::Result
+where
+    F: Display,
+    E: Fn(usize, usize) -> F,
+{
+    let cells = (0..rows)
+        .map(|i| {
+            (0..columns)
+                .map(|j| match f.precision() {
+                    Some(p) => format!("{:.*}", p, element(i, j)),
+                    None => format!("{}", element(i, j)),
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let widths = (0..columns)
+        .map(|j| cells.iter().map(|row| row[j].len()).max().unwrap_or(0))
+        .collect::<Vec<_>>();
+
+    for (i, row) in cells.iter().enumerate() {
+        if i > 0 {
+            writeln!(f)?;
+        }
+        write!(f, "[")?;
+        for (cell, width) in row.iter().zip(&widths) {
+            write!(f, " {:>1$}", cell, width)?;
+        }
+        write!(f, " ]")?;
+    }
+    Ok(())
+}
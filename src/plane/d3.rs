@@ -0,0 +1,73 @@
+use crate::vec::{Vec3, Vec4};
+use crate::{Real, Scalar};
+
+pub type Plane3f = Plane3<f32>;
+pub type Plane3d = Plane3<f64>;
+
+/// Plane in 3D space defined by the equation `normal.dot(p) + d = 0`.
+#[derive(Debug, PartialEq, Default, Clone, Copy)]
+#[repr(C)]
+pub struct Plane3<F>
+where
+    F: Scalar,
+{
+    pub normal: Vec3<F>,
+    pub d: F,
+}
+
+impl<F> Plane3<F>
+where
+    F: Scalar,
+{
+    /// Creates new [`Plane3`] from `normal` and `d` coefficient.
+    #[inline]
+    pub const fn new(normal: Vec3<F>, d: F) -> Self {
+        Self { normal, d }
+    }
+
+    /// Creates new [`Plane3`] passing through `point` with the given `normal`.
+    #[inline]
+    pub fn from_point_normal(point: Vec3<F>, normal: Vec3<F>) -> Self {
+        Self {
+            normal,
+            d: -normal.dot(point),
+        }
+    }
+
+    /// Creates new [`Plane3`] from the `(a, b, c, d)` coefficients of the plane equation.
+    #[inline]
+    pub fn from_vec4(v: Vec4<F>) -> Self {
+        Self {
+            normal: v.reduce(),
+            d: v.w,
+        }
+    }
+
+    /// Computes the signed distance from the plane to the `point`.
+    /// Distance is positive on the side the normal points to.
+    /// The plane is expected to be normalized.
+    /// ```
+    /// # use ewq::{plane::Plane3f, vecf};
+    /// let plane = Plane3f::from_point_normal(vecf!(0, 0, 1), vecf!(0, 0, 1));
+    /// assert_eq!(plane.signed_distance(vecf!(5, 5, 3)), 2.);
+    /// ```
+    #[inline]
+    pub fn signed_distance(&self, point: Vec3<F>) -> F {
+        self.normal.dot(point) + self.d
+    }
+}
+
+impl<F> Plane3<F>
+where
+    F: Real,
+{
+    /// Returns the copy of the plane with normal of unit length.
+    #[inline]
+    pub fn normalized(&self) -> Self {
+        let l = self.normal.magnitude();
+        Self {
+            normal: self.normal / l,
+            d: self.d / l,
+        }
+    }
+}
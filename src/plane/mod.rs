@@ -0,0 +1,2 @@
+mod d3;
+pub use d3::*;
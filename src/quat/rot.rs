@@ -1,6 +1,8 @@
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
-use num_traits::Float;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use crate::{scalar::mul_add, scalar::relative_eq, Real, Scalar};
 use crate::vec::Vec3;
+use crate::mat::{Mat3, Mat4};
+use crate::ang::Ang3;
 
 pub type Quatf = Quat<f32>;
 pub type Quatd = Quat<f64>;
@@ -9,7 +11,7 @@ pub type Quatd = Quat<f64>;
 #[repr(C)]
 pub struct Quat<F>
 where
-    F: Float,
+    F: Scalar,
 {
     /// Vector part.
     pub v: Vec3<F>,
@@ -19,11 +21,11 @@ where
 
 impl<F> Quat<F>
 where
-    F: Float,
+    F: Scalar,
 {
     /// Creates new quaternion.
     #[inline]
-    pub fn new(v: Vec3<F>, w: F) -> Self {
+    pub const fn new(v: Vec3<F>, w: F) -> Self {
         Self { v, w }
     }
 
@@ -33,36 +35,13 @@ where
         Self { v, w: F::zero() }
     }
 
-    /// Creates new quaternion that represents a rotation around `axis` by `angle` radians.
-    /// Method can accept denormalized input.
-    #[inline]
-    pub fn new_axis_rotation(axis: Vec3<F>, angle: F) -> Self {
-        Self {
-            v: axis.normalized() * angle.div(F::from(2).unwrap()).sin(),
-            w: angle.div(F::from(2).unwrap()).cos(),
-        }
-    }
-
-    /// Creates new quaternion that represents a rotation that moves vector `from` to vector `to`.
-    #[inline]
-    pub fn new_dst_rotation(from: Vec3<F>, to: Vec3<F>) -> Self {
-        let normal = from.cross(to);
-        let angle = to.dot_normalized(from).acos();
-        Self::new_axis_rotation(normal, angle)
-    }
-
-    /// Recovers angle axis representation of the quaternion.
-    #[inline]
-    pub fn into_axis_angle(self) -> (Vec3<F>, F) {
-        (
-            self.v.normalized(),
-            F::atan2(self.v.magnitude(), self.w) * F::from(2).unwrap(),
-        )
-    }
-
     /// Rotates vector `v` by the rotation defined by this quaternion.
     #[inline]
     pub fn rotate(&self, v: Vec3<F>) -> Vec3<F> {
+        #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+        if let Some(r) = crate::simd::quat_rotate(*self, v) {
+            return r;
+        }
         self.product(Self::new_vector(v))
             .product(self.reciprocal())
             .v
@@ -95,6 +74,24 @@ where
         }
     }
 
+    /// Creates new quaternion from `[x, y, z, w]` array.
+    #[inline]
+    pub fn from_array(xyzw: [F; 4]) -> Self {
+        Self::from_parts(xyzw[0], xyzw[1], xyzw[2], xyzw[3])
+    }
+
+    /// Converts quaternion into `[x, y, z, w]` array.
+    /// ```
+    /// # use ewq::Quatf;
+    /// let q = Quatf::from_array([1., 2., 3., 4.]);
+    /// assert_eq!(q.w, 4.);
+    /// assert_eq!(q.to_array(), [1., 2., 3., 4.]);
+    /// ```
+    #[inline]
+    pub fn to_array(&self) -> [F; 4] {
+        [self.v.x, self.v.y, self.v.z, self.w]
+    }
+
     /// Computes the conjugate of the quaternion.
     #[inline]
     pub fn conjugate(&self) -> Self {
@@ -104,10 +101,16 @@ where
         }
     }
 
-    /// Computes the norm of the quaternion.
+    /// Computes the dot product between two quaternions.
+    /// ```
+    /// # use ewq::Quatf;
+    /// let a = Quatf::from_parts(1., 2., 3., 4.);
+    /// assert_eq!(a.dot(Quatf::identity()), 4.);
+    /// assert_eq!(a.dot(a), a.sqrt_norm());
+    /// ```
     #[inline]
-    pub fn norm(&self) -> F {
-        F::sqrt(self.sqrt_norm())
+    pub fn dot(&self, other: Self) -> F {
+        mul_add(self.w, other.w, self.v.dot(other.v))
     }
 
     /// Computes the squared norm of the quaternion.
@@ -116,12 +119,6 @@ where
         self.v.sqrt_magnitude() + self.w * self.w
     }
 
-    /// Normalizes quaternion preserving direction but reducing its norm to `1`.
-    #[inline]
-    pub fn normalize(&self) -> Self {
-        *self / self.norm()
-    }
-
     /// Computes the reciprocal of the quaternion.
     #[inline]
     pub fn reciprocal(&self) -> Self {
@@ -155,9 +152,479 @@ where
     }
 }
 
+impl<F> Quat<F>
+where
+    F: Real,
+{
+    /// Creates new quaternion that represents a rotation around `axis` by `angle` radians.
+    /// Method can accept denormalized input.
+    #[inline]
+    pub fn new_axis_rotation(axis: Vec3<F>, angle: F) -> Self {
+        Self {
+            v: axis.normalized() * angle.div(F::from(2).unwrap()).sin(),
+            w: angle.div(F::from(2).unwrap()).cos(),
+        }
+    }
+
+    /// Creates new quaternion that represents a rotation that moves vector `from` to vector `to`.
+    #[inline]
+    pub fn new_dst_rotation(from: Vec3<F>, to: Vec3<F>) -> Self {
+        let normal = from.cross(to);
+        let angle = to.dot_normalized(from).acos();
+        Self::new_axis_rotation(normal, angle)
+    }
+
+    /// Creates new quaternion that represents a rotation of [`Vec3::forward`] onto `forward`
+    /// with [`Vec3::top`] being rotated as close as possible to `up`.
+    /// Method can accept denormalized input, but `forward` and `up` must not be parallel.
+    /// ```
+    /// # use ewq::{vec::Vec3d, Quatd};
+    /// let q = Quatd::look_rotation(Vec3d::new(0., 2., 0.), Vec3d::top());
+    /// assert!((q.rotate(Vec3d::forward()) - Vec3d::new(0., 1., 0.)).magnitude() < 1e-12);
+    /// assert!((q.rotate(Vec3d::top()) - Vec3d::top()).magnitude() < 1e-12);
+    /// ```
+    #[inline]
+    pub fn look_rotation(forward: Vec3<F>, up: Vec3<F>) -> Self {
+        let f = forward.normalized();
+        let l = up.cross(f).normalized();
+        let u = f.cross(l);
+        Self::from_mat3(&Mat3::from_columns(f, l, u))
+    }
+
+    /// Creates new quaternion that represents the shortest rotation that moves vector `from`
+    /// to vector `to`. Unlike [`Quat::new_dst_rotation`] handles opposite vectors by rotating
+    /// around an arbitrary perpendicular axis and avoids trigonometry.
+    /// Method can accept denormalized input.
+    /// ```
+    /// # use ewq::{vec::Vec3d, Quatd};
+    /// let from = Vec3d::new(1., 2., 3.);
+    /// let q = Quatd::new_shortest_rotation(from, -from);
+    /// assert!((q.rotate(from) + from).magnitude() < 1e-12);
+    /// assert_eq!(Quatd::new_shortest_rotation(from, from), Quatd::identity());
+    /// ```
+    pub fn new_shortest_rotation(from: Vec3<F>, to: Vec3<F>) -> Self {
+        let (from, to) = (from.normalized(), to.normalized());
+        let w = F::one() + from.dot(to);
+
+        if w <= F::epsilon() {
+            return Self::new_vector(from.any_orthogonal());
+        }
+
+        Self::new(from.cross(to), w).normalize()
+    }
+
+    /// Recovers angle axis representation of the quaternion.
+    #[inline]
+    pub fn into_axis_angle(self) -> (Vec3<F>, F) {
+        (
+            self.v.normalized(),
+            F::atan2(self.v.magnitude(), self.w) * F::from(2).unwrap(),
+        )
+    }
+
+    /// Computes the angle in radians of the shortest rotation from `self` to `other`.
+    /// Both quaternions are expected to be normalized.
+    /// ```
+    /// # use ewq::{vec::Vec3d, Quatd};
+    /// let a = Quatd::new_axis_rotation(Vec3d::top(), 0.5);
+    /// let b = Quatd::new_axis_rotation(Vec3d::top(), 2.);
+    /// assert!((a.angle_to(b) - 1.5).abs() < 1e-12);
+    /// ```
+    #[inline]
+    pub fn angle_to(&self, other: Self) -> F {
+        let d = self.conjugate().product(other);
+        F::atan2(d.v.magnitude(), d.w.abs()) * F::from(2).unwrap()
+    }
+
+    /// Rotates `self` towards `target` along the shortest path by at most `max_angle` radians.
+    /// Returns `target` if it is closer than `max_angle`.
+    /// Both quaternions are expected to be normalized.
+    /// ```
+    /// # use ewq::{vec::Vec3d, Quatd};
+    /// let a = Quatd::identity();
+    /// let b = Quatd::new_axis_rotation(Vec3d::top(), 2.);
+    /// let c = a.rotate_towards(b, 0.5);
+    /// assert!((c - Quatd::new_axis_rotation(Vec3d::top(), 0.5)).norm() < 1e-12);
+    /// assert_eq!(c.rotate_towards(b, 4.), b);
+    /// ```
+    pub fn rotate_towards(&self, target: Self, max_angle: F) -> Self {
+        let angle = self.angle_to(target);
+        if angle <= max_angle {
+            return target;
+        }
+
+        let mut d = self.conjugate().product(target);
+        if d.w < F::zero() {
+            d = -d;
+        }
+        self.product(d.powf(max_angle / angle)).normalize()
+    }
+
+    /// Spherically interpolates between two rotations along the shortest path,
+    /// `t = 0` yields `self` and `t = 1` yields `other`.
+    /// Both quaternions are expected to be normalized.
+    /// ```
+    /// # use ewq::{vec::Vec3d, Quatd};
+    /// let a = Quatd::identity();
+    /// let b = Quatd::new_axis_rotation(Vec3d::top(), 2.);
+    /// let c = a.slerp(b, 0.25);
+    /// assert!((c - Quatd::new_axis_rotation(Vec3d::top(), 0.5)).norm() < 1e-12);
+    /// ```
+    pub fn slerp(&self, other: Self, t: F) -> Self {
+        let mut other = other;
+        let mut cos = self.dot(other);
+        if cos < F::zero() {
+            other = -other;
+            cos = -cos;
+        }
+
+        if cos > F::one() - F::epsilon() {
+            return (*self + (other - *self) * t).normalize();
+        }
+
+        let theta = cos.acos();
+        let sin = theta.sin();
+        (*self * ((F::one() - t) * theta).sin() + other * (t * theta).sin()) / sin
+    }
+
+    /// Computes the intermediate control point of `self` for [`Quat::squad`] interpolation
+    /// given the previous and the next keyframes.
+    /// All quaternions are expected to be normalized.
+    pub fn squad_intermediate(&self, prev: Self, next: Self) -> Self {
+        let inv = self.conjugate();
+        let prev = if self.dot(prev) < F::zero() {
+            -prev
+        } else {
+            prev
+        };
+        let next = if self.dot(next) < F::zero() {
+            -next
+        } else {
+            next
+        };
+
+        let sum = inv.product(next).log() + inv.product(prev).log();
+        self.product((sum * -F::from(0.25).unwrap()).exp())
+    }
+
+    /// Spherical cubic interpolation between `self` and `other` keyframes, where `a` and `b`
+    /// are their intermediate control points computed with [`Quat::squad_intermediate`].
+    /// `t = 0` yields `self` and `t = 1` yields `other`.
+    /// ```
+    /// # use ewq::{vec::Vec3d, Quatd};
+    /// let keys = [0., 1., 2., 3.].map(|a| Quatd::new_axis_rotation(Vec3d::top(), a));
+    /// let a = keys[1].squad_intermediate(keys[0], keys[2]);
+    /// let b = keys[2].squad_intermediate(keys[1], keys[3]);
+    /// let q = keys[1].squad(keys[2], a, b, 0.5);
+    /// assert!((q - Quatd::new_axis_rotation(Vec3d::top(), 1.5)).norm() < 1e-12);
+    /// ```
+    #[inline]
+    pub fn squad(&self, other: Self, a: Self, b: Self, t: F) -> Self {
+        let two = F::from(2).unwrap();
+        self.slerp(other, t)
+            .slerp(a.slerp(b, t), two * t * (F::one() - t))
+    }
+
+    /// Integrates world space `angular_velocity` in radians per second over `dt` seconds
+    /// using the exponential map, returning the updated orientation.
+    /// ```
+    /// # use ewq::{vec::Vec3d, Quatd};
+    /// let q = Quatd::identity().integrate(Vec3d::new(0., 0., 2.), 0.25);
+    /// assert!((q - Quatd::new_axis_rotation(Vec3d::top(), 0.5)).norm() < 1e-12);
+    /// ```
+    #[inline]
+    pub fn integrate(&self, angular_velocity: Vec3<F>, dt: F) -> Self {
+        let half = dt / F::from(2).unwrap();
+        Self::new_vector(angular_velocity * half)
+            .exp()
+            .product(*self)
+            .normalize()
+    }
+
+    /// Rotates vector `v` by the rotation defined by this quaternion.
+    /// Faster than [`Quat::rotate`], but requires the quaternion to be normalized.
+    /// ```
+    /// # use ewq::{vec::Vec3d, Quatd};
+    /// let q = Quatd::new_axis_rotation(Vec3d::new(1., 2., 3.), 0.5);
+    /// let v = Vec3d::new(3., 2., 1.);
+    /// assert!((q.rotate_unit(v) - q.rotate(v)).magnitude() < 1e-12);
+    /// ```
+    #[inline]
+    pub fn rotate_unit(&self, v: Vec3<F>) -> Vec3<F> {
+        #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+        if let Some(r) = crate::simd::quat_rotate_unit(*self, v) {
+            return r;
+        }
+        let t = self.v.cross(v) * F::from(2).unwrap();
+        v + t * self.w + self.v.cross(t)
+    }
+
+    /// Converts quaternion into rotation matrix.
+    /// See [`Mat3::from_quat`].
+    #[inline]
+    pub fn to_mat3(&self) -> Mat3<F> {
+        Mat3::from_quat(*self)
+    }
+
+    /// Converts rotation quaternion into angles in order Yaw, Pitch, Roll.
+    /// Inverse of [`Ang3::into_rotation`], resulting pitch lies in `[-PI/2, PI/2]`.
+    /// In gimbal lock, when pitch is close to `PI/2` in magnitude, roll is set to `0`.
+    /// ```
+    /// # use ewq::ang::Ang3;
+    /// let a = Ang3::<f64>::new(0.3, -0.6, 1.2);
+    /// let b = a.into_rotation().to_euler();
+    /// assert!((a.yaw - b.yaw).abs() < 1e-12);
+    /// assert!((a.pitch - b.pitch).abs() < 1e-12);
+    /// assert!((a.roll - b.roll).abs() < 1e-12);
+    /// ```
+    pub fn to_euler(&self) -> Ang3<F> {
+        let m = self.to_mat3();
+        let (r0, r1, r2) = (m.row::<0>(), m.row::<1>(), m.row::<2>());
+        let cos_pitch = r0.x.hypot(r0.y);
+        let pitch = -r0.z.atan2(cos_pitch);
+
+        if cos_pitch <= F::epsilon() * F::from(16).unwrap() {
+            Ang3::new(r1.x.atan2(r1.y), pitch, F::zero())
+        } else {
+            Ang3::new((-r0.y).atan2(r0.x), pitch, (-r1.z).atan2(r2.z))
+        }
+    }
+
+    /// Converts quaternion into homogeneous rotation matrix.
+    /// ```
+    /// # use ewq::{vec::{Vec3d, Vec4d}, Quatd};
+    /// let q = Quatd::new_axis_rotation(Vec3d::new(1., 2., 3.), 0.5);
+    /// let v = q.to_mat4() * Vec4d::new(3., 2., 1., 1.);
+    /// assert!((v.reduce() - q.rotate(Vec3d::new(3., 2., 1.))).magnitude() < 1e-12);
+    /// ```
+    #[inline]
+    pub fn to_mat4(&self) -> Mat4<F> {
+        Mat4::from(self.to_mat3())
+    }
+
+    /// Creates new quaternion from the rotation matrix.
+    /// `m` is expected to be orthonormal with determinant `1`.
+    /// ```
+    /// # use ewq::{vec::Vec3d, Quatd};
+    /// let q = Quatd::new_axis_rotation(Vec3d::new(1., 2., 3.), 3.);
+    /// assert!((Quatd::from_mat3(&q.to_mat3()) - q).norm() < 1e-12);
+    /// ```
+    pub fn from_mat3(m: &Mat3<F>) -> Self {
+        let (two, quarter) = (F::from(2).unwrap(), F::from(0.25).unwrap());
+        let (r0, r1, r2) = (m.row::<0>(), m.row::<1>(), m.row::<2>());
+        let trace = m.trace();
+
+        if trace > F::zero() {
+            let s = (trace + F::one()).sqrt() * two;
+            Self::from_parts(
+                (r2.y - r1.z) / s,
+                (r0.z - r2.x) / s,
+                (r1.x - r0.y) / s,
+                quarter * s,
+            )
+        } else if r0.x > r1.y && r0.x > r2.z {
+            let s = (F::one() + r0.x - r1.y - r2.z).sqrt() * two;
+            Self::from_parts(
+                quarter * s,
+                (r0.y + r1.x) / s,
+                (r0.z + r2.x) / s,
+                (r2.y - r1.z) / s,
+            )
+        } else if r1.y > r2.z {
+            let s = (F::one() + r1.y - r0.x - r2.z).sqrt() * two;
+            Self::from_parts(
+                (r0.y + r1.x) / s,
+                quarter * s,
+                (r1.z + r2.y) / s,
+                (r0.z - r2.x) / s,
+            )
+        } else {
+            let s = (F::one() + r2.z - r0.x - r1.y).sqrt() * two;
+            Self::from_parts(
+                (r0.z + r2.x) / s,
+                (r1.z + r2.y) / s,
+                quarter * s,
+                (r1.x - r0.y) / s,
+            )
+        }
+    }
+
+    /// Checks if all components of two quaternions differ by no more than `epsilon`.
+    /// Note that `q` and `-q` represent the same rotation but are not considered equal.
+    #[inline]
+    pub fn abs_diff_eq(&self, other: &Self, epsilon: F) -> bool {
+        self.v.abs_diff_eq(&other.v, epsilon) && (self.w - other.w).abs() <= epsilon
+    }
+
+    /// Checks if all components of two quaternions are equal within `epsilon`
+    /// or within `max_relative` of the larger component magnitude.
+    #[inline]
+    pub fn relative_eq(&self, other: &Self, epsilon: F, max_relative: F) -> bool {
+        self.v.relative_eq(&other.v, epsilon, max_relative)
+            && relative_eq(self.w, other.w, epsilon, max_relative)
+    }
+
+    /// Converts the quaternion to the other floating point precision.
+    /// ```
+    /// # use ewq::{Quatd, Quatf};
+    /// assert_eq!(Quatd::identity().cast::<f32>(), Quatf::identity());
+    /// ```
+    #[inline]
+    pub fn cast<T>(&self) -> Quat<T>
+    where
+        T: Real,
+    {
+        Quat::new(self.v.cast(), T::from(self.w).unwrap())
+    }
+
+    /// Computes the norm of the quaternion.
+    #[inline]
+    pub fn norm(&self) -> F {
+        F::sqrt(self.sqrt_norm())
+    }
+
+    /// Normalizes quaternion preserving direction but reducing its norm to `1`.
+    #[inline]
+    pub fn normalize(&self) -> Self {
+        *self / self.norm()
+    }
+
+    /// Normalizes quaternion or returns `None` if its norm is too close to zero.
+    /// ```
+    /// # use ewq::Quatf;
+    /// assert_eq!(Quatf::zero().try_normalize(), None);
+    /// assert_eq!((Quatf::identity() * 2.).try_normalize(), Some(Quatf::identity()));
+    /// ```
+    #[inline]
+    pub fn try_normalize(&self) -> Option<Self> {
+        let n = self.norm();
+        if n > F::epsilon() {
+            Some(*self / n)
+        } else {
+            None
+        }
+    }
+
+    /// Checks if the squared norm of the quaternion differs from `1` by no more than `epsilon`.
+    #[inline]
+    pub fn is_normalized(&self, epsilon: F) -> bool {
+        (self.sqrt_norm() - F::one()).abs() <= epsilon
+    }
+
+    /// Checks if all components of the quaternion are finite.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.v.is_finite() && self.w.is_finite()
+    }
+
+    /// Checks if any component of the quaternion is `NaN`.
+    /// ```
+    /// # use ewq::Quatf;
+    /// assert!(!Quatf::identity().is_nan());
+    /// assert!(Quatf::zero().normalize().is_nan());
+    /// ```
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.v.is_nan() || self.w.is_nan()
+    }
+
+    /// Normalizes quaternion that is expected to be close to unit length.
+    /// Small drift is corrected with a first order approximation avoiding the square root,
+    /// falling back to [`Quat::normalize`] when the drift is large.
+    /// ```
+    /// # use ewq::Quatd;
+    /// let q = Quatd::from_parts(0., 0., 0., 1.001).renormalize();
+    /// assert!(q.is_normalized(1e-5));
+    /// ```
+    #[inline]
+    pub fn renormalize(&self) -> Self {
+        let n = self.sqrt_norm();
+        let drift = (n - F::one()).abs();
+        if drift <= F::epsilon() {
+            *self
+        } else if drift < F::from(1e-2).unwrap() {
+            *self * ((F::from(3).unwrap() - n) / F::from(2).unwrap())
+        } else {
+            self.normalize()
+        }
+    }
+
+    /// Computes the exponential of the quaternion.
+    #[inline]
+    pub fn exp(&self) -> Self {
+        let angle = self.v.magnitude();
+        let scale = self.w.exp();
+        if angle == F::zero() {
+            return Self::new(Vec3::zero(), scale);
+        }
+
+        let (sin, cos) = angle.sin_cos();
+        Self {
+            v: self.v * (scale * sin / angle),
+            w: scale * cos,
+        }
+    }
+
+    /// Computes the natural logarithm of the quaternion.
+    /// For the unit quaternion the result is a pure vector quaternion of half the rotation angle.
+    #[inline]
+    pub fn log(&self) -> Self {
+        let length = self.v.magnitude();
+        let w = self.norm().ln();
+        if length == F::zero() {
+            return Self::new(Vec3::zero(), w);
+        }
+
+        Self {
+            v: self.v * (length.atan2(self.w) / length),
+            w,
+        }
+    }
+
+    /// Raises the quaternion to the power `t`.
+    /// For the unit quaternion this scales the rotation angle by `t`.
+    /// ```
+    /// # use ewq::{vec::Vec3d, Quatd};
+    /// let q = Quatd::new_axis_rotation(Vec3d::top(), 2.);
+    /// let p = q.powf(0.25);
+    /// assert!((p - Quatd::new_axis_rotation(Vec3d::top(), 0.5)).norm() < 1e-12);
+    /// assert!((p.log().exp() - p).norm() < 1e-12);
+    /// ```
+    #[inline]
+    pub fn powf(&self, t: F) -> Self {
+        (self.log() * t).exp()
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<F> Quat<F>
+where
+    F: Real,
+{
+    /// Samples uniformly distributed rotation using the subgroup algorithm.
+    /// ```
+    /// # use ewq::Quatd;
+    /// let q = Quatd::random_uniform(&mut rand::thread_rng());
+    /// assert!(q.is_normalized(1e-12));
+    /// ```
+    pub fn random_uniform<R>(rng: &mut R) -> Self
+    where
+        R: rand::Rng + ?Sized,
+    {
+        let tau = F::from(std::f64::consts::TAU).unwrap();
+        let mut sample = || F::from(rng.gen::<f64>()).unwrap();
+        let (u1, u2, u3) = (sample(), sample(), sample());
+
+        let (a, b) = ((F::one() - u1).sqrt(), u1.sqrt());
+        let (sin2, cos2) = (tau * u2).sin_cos();
+        let (sin3, cos3) = (tau * u3).sin_cos();
+        Self::from_parts(a * sin2, a * cos2, b * sin3, b * cos3)
+    }
+}
+
 impl<F> Add for Quat<F>
 where
-    F: Float,
+    F: Scalar,
 {
     type Output = Self;
 
@@ -172,7 +639,7 @@ where
 
 impl<F> AddAssign for Quat<F>
 where
-    F: Float,
+    F: Scalar,
 {
     #[inline]
     fn add_assign(&mut self, rhs: Self) {
@@ -183,7 +650,7 @@ where
 
 impl<F> Sub for Quat<F>
 where
-    F: Float,
+    F: Scalar,
 {
     type Output = Self;
 
@@ -198,7 +665,7 @@ where
 
 impl<F> SubAssign for Quat<F>
 where
-    F: Float,
+    F: Scalar,
 {
     #[inline]
     fn sub_assign(&mut self, rhs: Self) {
@@ -209,7 +676,7 @@ where
 
 impl<F> Mul<F> for Quat<F>
 where
-    F: Float,
+    F: Scalar,
 {
     type Output = Self;
 
@@ -222,9 +689,52 @@ where
     }
 }
 
+impl<F> Mul for Quat<F>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    /// Computes the hamilton product. Same as [`Quat::product`].
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.product(rhs)
+    }
+}
+
+impl<F> MulAssign for Quat<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.product(rhs);
+    }
+}
+
+impl<F> Mul<Vec3<F>> for Quat<F>
+where
+    F: Scalar,
+{
+    type Output = Vec3<F>;
+
+    /// Rotates the vector. Same as [`Quat::rotate`].
+    /// ```
+    /// # use ewq::{vec::Vec3f, Quatf};
+    /// let a = Quatf::new_axis_rotation(Vec3f::top(), 1.);
+    /// let b = Quatf::new_axis_rotation(Vec3f::forward(), 2.);
+    /// let v = Vec3f::new(1., 2., 3.);
+    /// assert!(((a * b) * v - a.rotate(b.rotate(v))).magnitude() < 1e-5);
+    /// ```
+    #[inline]
+    fn mul(self, rhs: Vec3<F>) -> Self::Output {
+        self.rotate(rhs)
+    }
+}
+
 impl<F> MulAssign<F> for Quat<F>
 where
-    F: Float,
+    F: Scalar,
 {
     #[inline]
     fn mul_assign(&mut self, rhs: F) {
@@ -235,7 +745,7 @@ where
 
 impl<F> Div<F> for Quat<F>
 where
-    F: Float,
+    F: Scalar,
 {
     type Output = Self;
 
@@ -250,7 +760,7 @@ where
 
 impl<F> DivAssign<F> for Quat<F>
 where
-    F: Float,
+    F: Scalar,
 {
     #[inline]
     fn div_assign(&mut self, rhs: F) {
@@ -258,3 +768,54 @@ where
         self.w = self.w / rhs;
     }
 }
+
+impl<F> Neg for Quat<F>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    /// Negates all components of the quaternion.
+    /// For the unit quaternion the result represents the same rotation.
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self {
+            v: -self.v,
+            w: -self.w,
+        }
+    }
+}
+
+macro_rules! quat_scalar_mul {
+    ($($f:ty),+) => {$(
+        impl Mul<Quat<$f>> for $f {
+            type Output = Quat<$f>;
+
+            /// Multiplies the quaternion by the scalar.
+            /// ```
+            /// # use ewq::Quat;
+            #[doc = concat!("let q = Quat::<", stringify!($f), ">::from_parts(1., 2., 3., 4.);")]
+            /// assert_eq!(2. * q, q * 2.);
+            /// assert_eq!(-q, q * -1.);
+            /// ```
+            #[inline]
+            fn mul(self, rhs: Quat<$f>) -> Self::Output {
+                rhs * self
+            }
+        }
+    )+};
+}
+
+quat_scalar_mul!(f32, f64);
+
+/// Generates constants for the floating point quaternion types.
+macro_rules! quat_consts {
+    ($($f:ty),+) => {$(
+        impl Quat<$f> {
+            /// Quaternion representing no rotation.
+            pub const IDENTITY: Self = Self::new(Vec3::new(0., 0., 0.), 1.);
+        }
+    )+};
+}
+
+quat_consts!(f32, f64);
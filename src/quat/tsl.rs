@@ -1,11 +1,16 @@
-use crate::{vec::Vec3, Quat};
-use num_traits::Float;
+use crate::{
+    mat::{Mat3, Mat3x4, Mat4},
+    vec::Vec3,
+    Quat,
+};
+use crate::{Real, Scalar};
+use std::ops::{Mul, MulAssign};
 
 /// Compound struct for rotation and translation.
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub struct QuatT<F>
 where
-    F: Float,
+    F: Scalar,
 {
     /// Quaternion rotation.
     pub q: Quat<F>,
@@ -15,11 +20,11 @@ where
 
 impl<F> QuatT<F>
 where
-    F: Float,
+    F: Scalar,
 {
     /// Creates new [`QuatT`] from rotation quaternion and translation vector.
     #[inline]
-    pub fn new(rotation: Quat<F>, translation: Vec3<F>) -> Self {
+    pub const fn new(rotation: Quat<F>, translation: Vec3<F>) -> Self {
         Self {
             q: rotation,
             t: translation,
@@ -35,6 +40,23 @@ where
         }
     }
 
+    /// Computes the inverse transformation.
+    /// Rotation is expected to be normalized.
+    /// ```
+    /// # use ewq::{vec::Vec3d, Quatd, QuatT};
+    /// let qt = QuatT::new(Quatd::new_axis_rotation(Vec3d::top(), 0.5), Vec3d::new(1., 2., 3.));
+    /// let v = Vec3d::new(3., 2., 1.);
+    /// assert!((qt.inverse().apply(qt.apply(v)) - v).magnitude() < 1e-12);
+    /// ```
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        let q = self.q.conjugate();
+        Self {
+            q,
+            t: -q.rotate(self.t),
+        }
+    }
+
     /// Applies rotation and then translation to the vector.
     #[inline]
     pub fn apply(&self, vector: Vec3<F>) -> Vec3<F> {
@@ -47,3 +69,178 @@ where
         self.q.rotate(vector + self.t)
     }
 }
+
+impl<F> QuatT<F>
+where
+    F: Real,
+{
+    /// Creates new [`QuatT`] placing the camera at `eye` and facing `target`, so that
+    /// [`Vec3::forward`] is mapped towards `target` and [`Vec3::top`] towards `up`.
+    /// Use [`QuatT::inverse`] to get the world to camera transformation.
+    /// ```
+    /// # use ewq::{vec::Vec3d, QuatT};
+    /// let qt = QuatT::look_at(Vec3d::new(1., 0., 0.), Vec3d::new(1., 5., 0.), Vec3d::top());
+    /// let v = qt.apply(Vec3d::forward());
+    /// assert!((v - Vec3d::new(1., 1., 0.)).magnitude() < 1e-12);
+    /// ```
+    #[inline]
+    pub fn look_at(eye: Vec3<F>, target: Vec3<F>, up: Vec3<F>) -> Self {
+        Self {
+            q: Quat::look_rotation(target - eye, up),
+            t: eye,
+        }
+    }
+
+    /// Creates new [`QuatT`] from the affine matrix.
+    /// Upper-left 3x3 block of the matrix is expected to be a pure rotation.
+    /// ```
+    /// # use ewq::{mat::Mat3x4d, vec::Vec3d, Quatd, QuatT};
+    /// let q = Quatd::new_axis_rotation(Vec3d::new(1., 2., 3.), 0.5);
+    /// let qt = QuatT::from_mat3x4(&Mat3x4d::from_quat_t(&QuatT::new(q, Vec3d::new(1., 2., 3.))));
+    /// assert!((qt.q - q).norm() < 1e-12);
+    /// assert_eq!(qt.t, Vec3d::new(1., 2., 3.));
+    /// ```
+    #[inline]
+    pub fn from_mat3x4(m: &Mat3x4<F>) -> Self {
+        Self {
+            q: Quat::from_mat3(&Mat3::from(*m)),
+            t: m.column::<3>(),
+        }
+    }
+
+    /// Converts [`QuatT`] into homogeneous matrix that rotates and then translates vectors.
+    #[inline]
+    pub fn to_mat4(&self) -> Mat4<F> {
+        Mat3x4::from_quat_t(self).into()
+    }
+
+    /// Interpolates between two rigid transformations along the screw motion,
+    /// `t = 0` yields `self` and `t = 1` yields `other`.
+    /// Rotations are expected to be normalized.
+    /// ```
+    /// # use ewq::{vec::Vec3d, Quatd, QuatT};
+    /// let a = QuatT::identity();
+    /// let b = QuatT::new(Quatd::new_axis_rotation(Vec3d::top(), 2.), Vec3d::new(0., 0., 4.));
+    /// let c = a.sclerp(b, 0.5);
+    /// assert!((c.q - Quatd::new_axis_rotation(Vec3d::top(), 1.)).norm() < 1e-12);
+    /// assert!((c.t - Vec3d::new(0., 0., 2.)).magnitude() < 1e-12);
+    /// ```
+    pub fn sclerp(&self, other: Self, t: F) -> Self {
+        let two = F::from(2).unwrap();
+        let mut relative = self.inverse() * other;
+        if relative.q.w < F::zero() {
+            relative.q = -relative.q;
+        }
+
+        let sin_half = relative.q.v.magnitude();
+        if sin_half <= F::epsilon() {
+            return *self * Self::new(Quat::identity(), relative.t * t);
+        }
+
+        let axis = relative.q.v / sin_half;
+        let half = sin_half.atan2(relative.q.w);
+        let along = axis * axis.dot(relative.t);
+        let across = relative.t - along;
+        let center = (across + axis.cross(across) / half.tan()) / two;
+
+        let q = Quat::new_axis_rotation(axis, half * two * t);
+        *self * Self::new(q, along * t + center - q.rotate(center))
+    }
+
+    /// Independently interpolates rotation with [`Quat::slerp`] and translation linearly,
+    /// `t = 0` yields `self` and `t = 1` yields `other`.
+    #[inline]
+    pub fn blend(&self, other: Self, t: F) -> Self {
+        Self {
+            q: self.q.slerp(other.q, t),
+            t: self.t + (other.t - self.t) * t,
+        }
+    }
+
+    /// Converts the [`QuatT`] to the other floating point precision.
+    /// ```
+    /// # use ewq::{vec::Vec3d, Quatd, QuatT};
+    /// let qt = QuatT::new(Quatd::identity(), Vec3d::new(1., 2., 3.)).cast::<f32>();
+    /// assert_eq!(qt.t, Vec3d::new(1., 2., 3.).cast());
+    /// ```
+    #[inline]
+    pub fn cast<T>(&self) -> QuatT<T>
+    where
+        T: Real,
+    {
+        QuatT::new(self.q.cast(), self.t.cast())
+    }
+}
+
+impl<F> Mul for QuatT<F>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    /// Composes two transformations, applying `rhs` first and then `self`.
+    /// ```
+    /// # use ewq::{vec::Vec3d, Quatd, QuatT};
+    /// let a = QuatT::new(Quatd::new_axis_rotation(Vec3d::top(), 0.5), Vec3d::new(1., 2., 3.));
+    /// let b = QuatT::new(Quatd::new_axis_rotation(Vec3d::forward(), 1.), Vec3d::new(3., 2., 1.));
+    /// let v = Vec3d::new(1., 1., 1.);
+    /// assert!(((a * b).apply(v) - a.apply(b.apply(v))).magnitude() < 1e-12);
+    /// ```
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            q: self.q.product(rhs.q),
+            t: self.apply(rhs.t),
+        }
+    }
+}
+
+impl<F> MulAssign for QuatT<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<F> Mul<Vec3<F>> for QuatT<F>
+where
+    F: Scalar,
+{
+    type Output = Vec3<F>;
+
+    /// Applies the transformation to the vector. Same as [`QuatT::apply`].
+    #[inline]
+    fn mul(self, rhs: Vec3<F>) -> Self::Output {
+        self.apply(rhs)
+    }
+}
+
+impl<F> Default for QuatT<F>
+where
+    F: Scalar,
+{
+    /// Creates new identity [`QuatT`].
+    /// ```
+    /// # use ewq::QuatT;
+    /// assert_eq!(QuatT::<f32>::default(), QuatT::identity());
+    /// ```
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+/// Generates constants for the floating point transform types.
+macro_rules! quatt_consts {
+    ($($f:ty),+) => {$(
+        impl QuatT<$f> {
+            /// Identity rotation with zero translation.
+            pub const IDENTITY: Self = Self::new(Quat::<$f>::IDENTITY, Vec3::new(0., 0., 0.));
+        }
+    )+};
+}
+
+quatt_consts!(f32, f64);
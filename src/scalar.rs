@@ -0,0 +1,101 @@
+use num_traits::{Float, Num};
+use std::ops::Neg;
+
+/// Scalar type of vectors, matrices and quaternions.
+/// Covers the arithmetic needed by constructors, operators and products,
+/// so fixed-point and software-float types can be used as well.
+/// The `'static` bound lets the `simd` feature recognize `f32` at compile time.
+/// Implemented automatically for every suitable type.
+/// ```
+/// # use ewq::vec::Vec3;
+/// let a = Vec3::new(1i64, 2, 3);
+/// assert_eq!(a.cross(Vec3::new(0, 0, 1)), Vec3::new(2, -1, 0));
+/// assert_eq!(a.dot(a), 14);
+/// ```
+pub trait Scalar: Num + Copy + PartialOrd + Neg<Output = Self> + 'static {}
+
+impl<T> Scalar for T where T: Num + Copy + PartialOrd + Neg<Output = T> + 'static {}
+
+/// Floating point [`Scalar`], required by methods involving
+/// square roots, trigonometry or epsilon comparisons.
+/// Implemented automatically for every [`Float`] type.
+pub trait Real: Scalar + Float {}
+
+impl<T> Real for T where T: Float + 'static {}
+
+/// Returns the smaller of `a` and `b`, or `a` if they are unordered.
+#[inline]
+pub(crate) fn min<F>(a: F, b: F) -> F
+where
+    F: Scalar,
+{
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+/// Returns the larger of `a` and `b`, or `a` if they are unordered.
+#[inline]
+pub(crate) fn max<F>(a: F, b: F) -> F
+where
+    F: Scalar,
+{
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+/// Checks if `a` and `b` differ by no more than `epsilon`,
+/// or by no more than `max_relative` times the larger of their magnitudes.
+#[inline]
+pub(crate) fn relative_eq<F>(a: F, b: F, epsilon: F, max_relative: F) -> bool
+where
+    F: Real,
+{
+    let diff = (a - b).abs();
+    diff <= epsilon || diff <= a.abs().max(b.abs()) * max_relative
+}
+
+/// Computes `a * b + c`, rounding once for `f32` and `f64` if the `fma` feature is enabled.
+/// Dot, cross and matrix products are accumulated with this.
+#[inline]
+pub(crate) fn mul_add<F>(a: F, b: F, c: F) -> F
+where
+    F: Scalar,
+{
+    #[cfg(feature = "fma")]
+    if let Some(r) = fused::<F, f32>(a, b, c).or_else(|| fused::<F, f64>(a, b, c)) {
+        return r;
+    }
+    a * b + c
+}
+
+/// Computes `a.mul_add(b, c)` if `F` is `T`; the check is resolved at compile time.
+#[cfg(feature = "fma")]
+#[inline(always)]
+fn fused<F, T>(a: F, b: F, c: F) -> Option<F>
+where
+    F: Scalar,
+    T: Real,
+{
+    use std::any::Any;
+    let &(a, b, c) = (&(a, b, c) as &dyn Any).downcast_ref::<(T, T, T)>()?;
+    (&a.mul_add(b, c) as &dyn Any).downcast_ref().copied()
+}
+
+/// Computes `a * b - c * d` with Kahan's algorithm, so the result
+/// is exactly zero when both products are equal, even if they are fused.
+#[inline]
+pub(crate) fn diff_of_products<F>(a: F, b: F, c: F, d: F) -> F
+where
+    F: Scalar,
+{
+    let w = c * d;
+    let e = mul_add(-c, d, w);
+    let f = mul_add(a, b, -w);
+    f + e
+}
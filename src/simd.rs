@@ -0,0 +1,92 @@
+//! SSE implementations of the `f32` hot paths.
+//!
+//! [`Vec3::normalized`], [`Quat::rotate`] and [`Quat::rotate_unit`] call into this module
+//! and fall back to the scalar code when `F` is not `f32`; the check is resolved at compile time.
+//! Compare with `cargo bench --bench simd` with and without the feature. Dot products
+//! and matrix products are left scalar, as LLVM already compiles them to packed SSE.
+//!
+//! Only x86_64 is accelerated; on other targets, including aarch64,
+//! the module is not compiled and the scalar code is used.
+use std::any::Any;
+use std::arch::x86_64::*;
+use crate::vec::Vec3;
+use crate::{Quat, Scalar};
+
+/// Returns `value` as `U` if `T` and `U` are the same type.
+#[inline(always)]
+fn cast<T: Any + Copy, U: Any + Copy>(value: T) -> Option<U> {
+    (&value as &dyn Any).downcast_ref().copied()
+}
+
+#[inline(always)]
+fn load3(v: Vec3<f32>) -> __m128 {
+    // SAFETY: SSE is part of the x86_64 baseline.
+    unsafe { _mm_set_ps(0., v.z, v.y, v.x) }
+}
+
+#[inline(always)]
+fn store3(a: __m128) -> Vec3<f32> {
+    // SAFETY: SSE is part of the x86_64 baseline.
+    unsafe {
+        Vec3::new(
+            _mm_cvtss_f32(a),
+            _mm_cvtss_f32(_mm_shuffle_ps(a, a, 0b11_10_01_01)),
+            _mm_cvtss_f32(_mm_movehl_ps(a, a)),
+        )
+    }
+}
+
+/// Sums the lanes of `a`, broadcasting the result into every lane.
+#[inline(always)]
+fn sum_lanes(a: __m128) -> __m128 {
+    // SAFETY: SSE is part of the x86_64 baseline.
+    unsafe {
+        let a = _mm_add_ps(a, _mm_shuffle_ps(a, a, 0b10_11_00_01));
+        _mm_add_ps(a, _mm_shuffle_ps(a, a, 0b01_00_11_10))
+    }
+}
+
+#[inline(always)]
+fn cross(a: __m128, b: __m128) -> __m128 {
+    // SAFETY: SSE is part of the x86_64 baseline.
+    unsafe {
+        let a_yzx = _mm_shuffle_ps(a, a, 0b11_00_10_01);
+        let b_yzx = _mm_shuffle_ps(b, b, 0b11_00_10_01);
+        let c = _mm_sub_ps(_mm_mul_ps(a, b_yzx), _mm_mul_ps(a_yzx, b));
+        _mm_shuffle_ps(c, c, 0b11_00_10_01)
+    }
+}
+
+#[inline(always)]
+fn normalize(a: __m128) -> __m128 {
+    // SAFETY: SSE is part of the x86_64 baseline.
+    unsafe { _mm_div_ps(a, _mm_sqrt_ps(sum_lanes(_mm_mul_ps(a, a)))) }
+}
+
+/// Computes `v + w * t + q × t` where `t = (q × v) * scale`.
+#[inline(always)]
+fn rotate(q: Quat<f32>, v: Vec3<f32>, scale: f32) -> Vec3<f32> {
+    let (qv, v) = (load3(q.v), load3(v));
+    // SAFETY: SSE is part of the x86_64 baseline.
+    unsafe {
+        let t = _mm_mul_ps(cross(qv, v), _mm_set1_ps(scale));
+        let r = _mm_add_ps(v, _mm_mul_ps(t, _mm_set1_ps(q.w)));
+        store3(_mm_add_ps(r, cross(qv, t)))
+    }
+}
+
+#[inline]
+pub(crate) fn vec3_normalized<F: Scalar>(a: Vec3<F>) -> Option<Vec3<F>> {
+    cast(store3(normalize(load3(cast(a)?))))
+}
+
+#[inline]
+pub(crate) fn quat_rotate<F: Scalar>(q: Quat<F>, v: Vec3<F>) -> Option<Vec3<F>> {
+    let q: Quat<f32> = cast(q)?;
+    cast(rotate(q, cast(v)?, 2. / (q.v.sqrt_magnitude() + q.w * q.w)))
+}
+
+#[inline]
+pub(crate) fn quat_rotate_unit<F: Scalar>(q: Quat<F>, v: Vec3<F>) -> Option<Vec3<F>> {
+    cast(rotate(cast(q)?, cast(v)?, 2.))
+}
@@ -1,6 +1,11 @@
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
-use num_traits::Float;
+use std::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::iter::{Product, Sum};
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+use crate::scalar::{diff_of_products, max, min, mul_add, relative_eq};
+use crate::{Real, Scalar};
 use crate::Complex;
+use super::{ParseVecError, Vec3};
 
 pub type Vec2f = Vec2<f32>;
 pub type Vec2d = Vec2<f64>;
@@ -10,7 +15,7 @@ pub type Vec2d = Vec2<f64>;
 #[repr(C)]
 pub struct Vec2<F>
 where
-    F: Float,
+    F: Scalar,
 {
     pub x: F,
     pub y: F,
@@ -18,11 +23,11 @@ where
 
 impl<F> Vec2<F>
 where
-    F: Float,
+    F: Scalar,
 {
     /// Creates new vector.
     #[inline(always)]
-    pub fn new(x: F, y: F) -> Self {
+    pub const fn new(x: F, y: F) -> Self {
         Self { x, y }
     }
 
@@ -56,9 +61,347 @@ where
     /// Computes dot product between two vectors.
     #[inline]
     pub fn dot(&self, other: Self) -> F {
-        self.x * other.x + self.y * other.y
+        mul_add(self.y, other.y, self.x * other.x)
     }
 
+    /// Computes the perp dot product, i.e. the Z component of the 3D cross product.
+    #[inline]
+    pub fn perp_dot(&self, other: Self) -> F {
+        diff_of_products(self.x, other.y, self.y, other.x)
+    }
+
+    /// Computes the 2D cross product, i.e. the Z component of the 3D cross product.
+    /// Positive if `other` is counter-clockwise from `self`. Same as [`Self::perp_dot`].
+    /// ```
+    /// # use ewq::vec::Vec2f;
+    /// let a = Vec2f::new(1., 0.);
+    /// let b = Vec2f::new(1., 1.);
+    /// assert_eq!(a.cross(b), 1.);
+    /// assert_eq!(b.cross(a), -1.);
+    /// assert_eq!(a.cross(a * 2.), 0.);
+    /// ```
+    #[inline]
+    pub fn cross(&self, other: Self) -> F {
+        self.perp_dot(other)
+    }
+
+    /// Computes barycentric coordinates of the point with respect to the triangle `a`, `b`, `c`.
+    /// Returns `None` if the triangle is degenerate.
+    /// ```
+    /// # use ewq::vec::{Vec2f, Vec3f};
+    /// let (a, b, c) = (Vec2f::new(0., 0.), Vec2f::new(4., 0.), Vec2f::new(0., 4.));
+    /// let weights = Vec2f::new(1., 2.).barycentric(a, b, c).unwrap();
+    /// assert_eq!(weights, Vec3f::new(0.25, 0.25, 0.5));
+    /// assert_eq!(weights.interpolate_barycentric(a, b, c), Vec2f::new(1., 2.));
+    /// assert_eq!(Vec2f::new(1., 2.).barycentric(a, b, b), None);
+    /// ```
+    #[inline]
+    pub fn barycentric(&self, a: Self, b: Self, c: Self) -> Option<Vec3<F>> {
+        let (ab, ac, ap) = (b - a, c - a, *self - a);
+        let (d00, d01, d11) = (ab.dot(ab), ab.dot(ac), ac.dot(ac));
+        let (d20, d21) = (ap.dot(ab), ap.dot(ac));
+        let denom = d00 * d11 - d01 * d01;
+        if denom == F::zero() {
+            return None;
+        }
+        let v = (d11 * d20 - d01 * d21) / denom;
+        let w = (d00 * d21 - d01 * d20) / denom;
+        Some(Vec3::new(F::one() - v - w, v, w))
+    }
+
+    /// Scales all of the components by `factor`.
+    #[inline]
+    pub fn scale(&mut self, factor: F) {
+        self.x = self.x * factor;
+        self.y = self.y * factor;
+    }
+
+    /// Computes the squared magnitude of the vector.
+    #[inline]
+    pub fn sqrt_magnitude(&self) -> F {
+        self.x * self.x + self.y * self.y
+    }
+
+    /// Linearly interpolates between two vectors.
+    /// `t = 0` yields `self` and `t = 1` yields `other`.
+    /// ```
+    /// # use ewq::vec::Vec2;
+    /// let a = Vec2::new(1., 1.);
+    /// let b = Vec2::new(2., 2.);
+    /// assert_eq!(a.lerp(b, 0.5), Vec2::new(1.5, 1.5));
+    /// assert_eq!(a.lerp(b, 0.), a);
+    /// assert_eq!(a.lerp(b, 1.), b);
+    /// ```
+    #[inline]
+    pub fn lerp(&self, other: Self, t: F) -> Self {
+        *self + (other - *self) * t
+    }
+
+    /// Linearly interpolates between two vectors with `t` clamped to `[0, 1]`,
+    /// so the result never leaves the segment between `self` and `other`.
+    #[inline]
+    pub fn lerp_clamped(&self, other: Self, t: F) -> Self {
+        let t = if t < F::zero() {
+            F::zero()
+        } else if t > F::one() {
+            F::one()
+        } else {
+            t
+        };
+        self.lerp(other, t)
+    }
+
+    /// Computes the interpolation factor `t` for which `a.lerp(b, t)` is the closest to `value`,
+    /// i.e. `0` at `a` and `1` at `b`. The result is not clamped.
+    /// `a` and `b` must not be equal.
+    #[inline]
+    pub fn inverse_lerp(a: Self, b: Self, value: Self) -> F {
+        let ab = b - a;
+        (value - a).dot(ab) / ab.dot(ab)
+    }
+
+    /// Linearly maps every component from the range `from` to the range `to`,
+    /// where ranges are given as `(start, end)` pairs of vectors.
+    #[inline]
+    pub fn remap(&self, from: (Self, Self), to: (Self, Self)) -> Self {
+        let r = |v: F, a: F, b: F, c: F, d: F| c + (v - a) * (d - c) / (b - a);
+        Self::new(
+            r(self.x, from.0.x, from.1.x, to.0.x, to.1.x),
+            r(self.y, from.0.y, from.1.y, to.0.y, to.1.y),
+        )
+    }
+
+    /// Reflects the vector off the surface with the given `normal`.
+    /// `normal` must be normalized.
+    /// ```
+    /// # use ewq::vec::Vec2;
+    /// let v = Vec2::new(1., -1.);
+    /// assert_eq!(v.reflect(Vec2::new(0., 1.)), Vec2::new(1., 1.));
+    /// ```
+    #[inline]
+    pub fn reflect(&self, normal: Self) -> Self {
+        *self - normal * ((F::one() + F::one()) * self.dot(normal))
+    }
+
+    /// Projects the vector onto `other`.
+    /// ```
+    /// # use ewq::vec::Vec2;
+    /// let v = Vec2::new(3., 4.);
+    /// let onto = Vec2::new(2., 0.);
+    /// assert_eq!(v.project_onto(onto), Vec2::new(3., 0.));
+    /// assert_eq!(v.project_onto(onto) + v.reject_from(onto), v);
+    /// ```
+    #[inline]
+    pub fn project_onto(&self, other: Self) -> Self {
+        other * (self.dot(other) / other.sqrt_magnitude())
+    }
+
+    /// Projects the vector onto `other`, which must be normalized.
+    #[inline]
+    pub fn project_onto_normalized(&self, other: Self) -> Self {
+        other * self.dot(other)
+    }
+
+    /// Returns the component of the vector perpendicular to `other`.
+    #[inline]
+    pub fn reject_from(&self, other: Self) -> Self {
+        *self - self.project_onto(other)
+    }
+
+    /// Returns the component of the vector perpendicular to `other`, which must be normalized.
+    #[inline]
+    pub fn reject_from_normalized(&self, other: Self) -> Self {
+        *self - self.project_onto_normalized(other)
+    }
+
+    /// Returns the vector rotated by 90 degrees counterclockwise.
+    /// ```
+    /// # use ewq::vec::Vec2;
+    /// assert_eq!(Vec2::new(1., 2.).perp(), Vec2::new(-2., 1.));
+    /// ```
+    #[inline]
+    pub fn perp(&self) -> Self {
+        Self {
+            x: -self.y,
+            y: self.x,
+        }
+    }
+
+    /// Performs cubic Hermite interpolation from `self` with tangent `tangent`
+    /// to `other` with tangent `other_tangent`.
+    /// ```
+    /// # use ewq::vec::Vec2f;
+    /// let a = Vec2f::new(0., 0.);
+    /// let b = Vec2f::new(2., 2.);
+    /// let m = Vec2f::new(0., 0.);
+    /// assert_eq!(a.hermite(m, b, m, 0.5), a.smoothstep(b, 0.5));
+    /// assert_eq!(a.hermite(m, b, m, 1.), b);
+    /// ```
+    #[inline]
+    pub fn hermite(&self, tangent: Self, other: Self, other_tangent: Self, t: F) -> Self {
+        let two = F::one() + F::one();
+        let three = two + F::one();
+        let (t2, t3) = (t * t, t * t * t);
+        *self * (two * t3 - three * t2 + F::one())
+            + tangent * (t3 - two * t2 + t)
+            + other * (three * t2 - two * t3)
+            + other_tangent * (t3 - t2)
+    }
+
+    /// Views the components of the vector as a slice.
+    /// ```
+    /// # use ewq::vec::Vec2f;
+    /// let mut v = Vec2f::new(1., 2.);
+    /// assert_eq!(v.as_slice(), &[1., 2.]);
+    /// v.as_mut_slice()[0] = 0.;
+    /// assert_eq!(v.x, 0.);
+    /// ```
+    #[inline]
+    pub fn as_slice(&self) -> &[F] {
+        // SAFETY: `#[repr(C)]` struct of 2 `F` fields has the same layout as `[F; 2]`.
+        unsafe { std::slice::from_raw_parts(self as *const Self as *const F, 2) }
+    }
+
+    /// Views the components of the vector as a mutable slice.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [F] {
+        // SAFETY: `#[repr(C)]` struct of 2 `F` fields has the same layout as `[F; 2]`.
+        unsafe { std::slice::from_raw_parts_mut(self as *mut Self as *mut F, 2) }
+    }
+
+    /// Returns an iterator over the components of the vector.
+    #[inline]
+    pub fn iter(&self) -> std::slice::Iter<'_, F> {
+        self.as_slice().iter()
+    }
+
+    /// Computes the sum of all components.
+    /// ```
+    /// # use ewq::vec::Vec2f;
+    /// let v = Vec2f::new(1., 2.);
+    /// assert_eq!(v.element_sum(), 3.);
+    /// assert_eq!(v.element_product(), 2.);
+    /// ```
+    #[inline]
+    pub fn element_sum(&self) -> F {
+        self.x + self.y
+    }
+
+    /// Computes the product of all components.
+    #[inline]
+    pub fn element_product(&self) -> F {
+        self.x * self.y
+    }
+
+    /// Returns the index of the smallest component of the vector.
+    #[inline]
+    pub fn min_axis(&self) -> usize {
+        (1..2).fold(0, |min, i| if self[i] < self[min] { i } else { min })
+    }
+
+    /// Returns the index of the largest component of the vector.
+    #[inline]
+    pub fn max_axis(&self) -> usize {
+        (1..2).fold(0, |max, i| if self[i] > self[max] { i } else { max })
+    }
+
+    /// Returns component-wise minimum of two vectors.
+    #[inline]
+    pub fn min(&self, other: Self) -> Self {
+        Self {
+            x: min(self.x, other.x),
+            y: min(self.y, other.y),
+        }
+    }
+
+    /// Returns component-wise maximum of two vectors.
+    #[inline]
+    pub fn max(&self, other: Self) -> Self {
+        Self {
+            x: max(self.x, other.x),
+            y: max(self.y, other.y),
+        }
+    }
+
+    /// Clamps each component of the vector between the corresponding components of `lo` and `hi`.
+    /// ```
+    /// # use ewq::vec::Vec2;
+    /// let v = Vec2::new(3., -1.);
+    /// let lo = Vec2::new(0., 0.);
+    /// let hi = Vec2::new(2., 2.);
+    /// assert_eq!(v.clamp(lo, hi), Vec2::new(2., 0.));
+    /// ```
+    #[inline]
+    pub fn clamp(&self, lo: Self, hi: Self) -> Self {
+        Self {
+            x: min(max(self.x, lo.x), hi.x),
+            y: min(max(self.y, lo.y), hi.y),
+        }
+    }
+
+    /// Returns the smallest component of the vector.
+    /// ```
+    /// # use ewq::vec::Vec2f;
+    /// let v = Vec2f::new(3., -1.);
+    /// assert_eq!(v.min_element(), -1.);
+    /// assert_eq!(v.max_element(), 3.);
+    /// assert_eq!(v.min_axis(), 1);
+    /// assert_eq!(v.max_axis(), 0);
+    /// ```
+    #[inline]
+    pub fn min_element(&self) -> F {
+        min(self.x, self.y)
+    }
+
+    /// Returns the largest component of the vector.
+    #[inline]
+    pub fn max_element(&self) -> F {
+        max(self.x, self.y)
+    }
+
+    /// Computes the least nonnegative remainder of every component
+    /// divided by the corresponding component of `rhs`.
+    /// ```
+    /// # use ewq::vec::Vec2f;
+    /// let v = Vec2f::new(-1., 7.);
+    /// assert_eq!(v.rem_euclid(Vec2f::new(4., 4.)), Vec2f::new(3., 3.));
+    /// ```
+    #[inline]
+    pub fn rem_euclid(&self, rhs: Self) -> Self {
+        let r = |a: F, b: F| {
+            let r = a % b;
+            if r >= F::zero() {
+                return r;
+            }
+            // Tiny negative remainders can round up to `|b|` itself.
+            let r = if b < F::zero() { r - b } else { r + b };
+            if r == b || r == -b {
+                F::zero()
+            } else {
+                r
+            }
+        };
+        Self::new(r(self.x, rhs.x), r(self.y, rhs.y))
+    }
+
+    /// Wraps every component into the range `[min, max)`,
+    /// e.g. positions in a tiling world or texture coordinates.
+    /// ```
+    /// # use ewq::vec::Vec2f;
+    /// let (min, max) = (Vec2f::new(-1., 0.), Vec2f::new(1., 1.));
+    /// assert_eq!(Vec2f::new(1.5, -0.25).wrap(min, max), Vec2f::new(-0.5, 0.75));
+    /// assert_eq!(Vec2f::new(-1e-9, 0.).wrap(Vec2f::ZERO, Vec2f::ONE), Vec2f::ZERO);
+    /// ```
+    #[inline]
+    pub fn wrap(&self, min: Self, max: Self) -> Self {
+        min + (*self - min).rem_euclid(max - min)
+    }
+}
+
+impl<F> Vec2<F>
+where
+    F: Real,
+{
     /// Computes dot product between two normalized copies of the vectors.
     #[inline]
     pub fn dot_normalized(&self, other: Self) -> F {
@@ -77,23 +420,35 @@ where
         F::acos(self.dot_normalized(other))
     }
 
-    /// Scales all of the components by `factor`.
+    /// Computes the signed angle from `self` to `other`, positive for counterclockwise rotation.
+    /// ```
+    /// # use ewq::vec::Vec2f;
+    /// let a = Vec2f::new(1., 0.);
+    /// let b = Vec2f::new(1., -1.);
+    /// assert_eq!(a.signed_angle_to(b), -std::f32::consts::FRAC_PI_4);
+    /// assert_eq!(b.signed_angle_to(a), std::f32::consts::FRAC_PI_4);
+    /// ```
     #[inline]
-    pub fn scale(&mut self, factor: F) {
-        self.x = self.x * factor;
-        self.y = self.y * factor;
+    pub fn signed_angle_to(&self, other: Self) -> F {
+        F::atan2(self.perp_dot(other), self.dot(other))
     }
 
-    /// Computes the magnitude of the vector.
+    /// Computes `self * a + b` component-wise,
+    /// rounding once for `f32` and `f64` if the `fma` feature is enabled.
+    /// ```
+    /// # use ewq::vec::Vec2f;
+    /// let v = Vec2f::new(1., 2.);
+    /// assert_eq!(v.mul_add(Vec2f::new(2., 2.), Vec2f::ONE), Vec2f::new(3., 5.));
+    /// ```
     #[inline]
-    pub fn magnitude(&self) -> F {
-        F::sqrt(self.sqrt_magnitude())
+    pub fn mul_add(&self, a: Self, b: Self) -> Self {
+        Self::new(mul_add(self.x, a.x, b.x), mul_add(self.y, a.y, b.y))
     }
 
-    /// Computes the squared magnitude of the vector.
+    /// Computes the magnitude of the vector.
     #[inline]
-    pub fn sqrt_magnitude(&self) -> F {
-        self.x * self.x + self.y * self.y
+    pub fn magnitude(&self) -> F {
+        F::sqrt(mul_add(self.y, self.y, self.x * self.x))
     }
 
     /// Computes the distance between two vectors.
@@ -112,16 +467,41 @@ where
         }
     }
 
-    /// Linearly interpolates between two vectors.
+    /// Returns the normalized version of the vector
+    /// or `None` if its magnitude is too close to zero.
     /// ```
-    /// # use ewq::vec::Vec2;
-    /// let a = Vec2::new(1., 1.);
-    /// let b = Vec2::new(2., 2.);
-    /// assert_eq!(a.lerp(b, 0.5), Vec2::new(1.5, 1.5));
+    /// # use ewq::vec::Vec2f;
+    /// assert_eq!(Vec2f::default().try_normalize(), None);
+    /// assert_eq!(Vec2f::new(3., 4.).try_normalize(), Some(Vec2f::new(3., 4.) / 5.));
     /// ```
     #[inline]
-    pub fn lerp(&self, other: Self, t: F) -> Self {
-        *self + (other - *self) * (F::one() - t)
+    pub fn try_normalize(&self) -> Option<Self> {
+        let l = self.magnitude();
+        if l > F::epsilon() {
+            Some(*self / l)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the normalized version of the vector
+    /// or zero vector if its magnitude is too close to zero.
+    #[inline]
+    pub fn normalize_or_zero(&self) -> Self {
+        self.normalize_or(Self::new(F::zero(), F::zero()))
+    }
+
+    /// Returns the normalized version of the vector
+    /// or `fallback` if its magnitude is too close to zero.
+    /// ```
+    /// # use ewq::vec::Vec2f;
+    /// let fallback = Vec2f::new(1., 0.);
+    /// assert_eq!(Vec2f::default().normalize_or(fallback), fallback);
+    /// assert_eq!(Vec2f::default().normalize_or_zero(), Vec2f::default());
+    /// ```
+    #[inline]
+    pub fn normalize_or(&self, fallback: Self) -> Self {
+        self.try_normalize().unwrap_or(fallback)
     }
 
     /// Normalizes the vector, preserving direction but reducing its magnitude to `1`.
@@ -131,11 +511,225 @@ where
         self.x = self.x / l;
         self.y = self.y / l;
     }
+
+    /// Creates new unit vector pointing in the direction of `angle` radians,
+    /// measured counterclockwise from the X axis.
+    /// ```
+    /// # use ewq::vec::Vec2d;
+    /// let v = Vec2d::from_angle(std::f64::consts::FRAC_PI_3);
+    /// assert!((v.angle() - std::f64::consts::FRAC_PI_3).abs() < 1e-12);
+    /// assert_eq!(Vec2d::new(0., -2.).angle(), -std::f64::consts::FRAC_PI_2);
+    /// ```
+    #[inline]
+    pub fn from_angle(angle: F) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self { x: cos, y: sin }
+    }
+
+    /// Computes the angle of the vector, measured counterclockwise from the X axis, in `(-π, π]` range.
+    #[inline]
+    pub fn angle(&self) -> F {
+        F::atan2(self.y, self.x)
+    }
+
+    /// Returns the vector rotated counterclockwise by `angle` radians.
+    /// ```
+    /// # use ewq::vec::Vec2;
+    /// let v = Vec2::new(1., 0.).rotated(std::f64::consts::FRAC_PI_2);
+    /// assert!((v - Vec2::new(0., 1.)).magnitude() < 1e-12);
+    /// ```
+    #[inline]
+    pub fn rotated(&self, angle: F) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self {
+            x: self.x * cos - self.y * sin,
+            y: self.x * sin + self.y * cos,
+        }
+    }
+
+    /// Returns the vector with its magnitude limited to `max`.
+    /// ```
+    /// # use ewq::vec::Vec2f;
+    /// let v = Vec2f::new(3., 4.);
+    /// assert_eq!(v.clamp_magnitude(1.), Vec2f::new(0.6, 0.8));
+    /// assert_eq!(v.clamp_magnitude(10.), v);
+    /// ```
+    #[inline]
+    pub fn clamp_magnitude(&self, max: F) -> Self {
+        let l = self.magnitude();
+        if l > max {
+            *self * (max / l)
+        } else {
+            *self
+        }
+    }
+
+    /// Returns the vector with the same direction and magnitude of `magnitude`.
+    /// Zero vector is returned as is.
+    /// ```
+    /// # use ewq::vec::Vec2f;
+    /// assert_eq!(Vec2f::new(3., 4.).with_magnitude(10.), Vec2f::new(6., 8.));
+    /// ```
+    #[inline]
+    pub fn with_magnitude(&self, magnitude: F) -> Self {
+        self.normalize_or_zero() * magnitude
+    }
+
+    /// Moves the vector toward `target` by no more than `max_delta` distance.
+    /// ```
+    /// # use ewq::vec::Vec2f;
+    /// let v = Vec2f::new(0., 0.);
+    /// let target = Vec2f::new(0., 10.);
+    /// assert_eq!(v.move_toward(target, 3.), Vec2f::new(0., 3.));
+    /// assert_eq!(v.move_toward(target, 20.), target);
+    /// ```
+    #[inline]
+    pub fn move_toward(&self, target: Self, max_delta: F) -> Self {
+        let delta = target - *self;
+        let l = delta.magnitude();
+        if l <= max_delta || l <= F::epsilon() {
+            target
+        } else {
+            *self + delta * (max_delta / l)
+        }
+    }
+
+    /// Interpolates between two vectors with smoothstep easing,
+    /// `t` is clamped to `[0, 1]` range.
+    /// ```
+    /// # use ewq::vec::Vec2f;
+    /// let a = Vec2f::new(0., 0.);
+    /// let b = Vec2f::new(2., 2.);
+    /// assert_eq!(a.smoothstep(b, 0.), a);
+    /// assert_eq!(a.smoothstep(b, 0.25), Vec2f::new(0.3125, 0.3125));
+    /// assert_eq!(a.smoothstep(b, 2.), b);
+    /// ```
+    #[inline]
+    pub fn smoothstep(&self, other: Self, t: F) -> Self {
+        let t = t.max(F::zero()).min(F::one());
+        let three = F::one() + F::one() + F::one();
+        *self + (other - *self) * (t * t * (three - (t + t)))
+    }
+
+    /// Checks if all components of the vector are finite.
+    /// ```
+    /// # use ewq::vec::Vec2f;
+    /// let v = Vec2f::new(1., f32::INFINITY);
+    /// assert!(!v.is_finite());
+    /// assert!(!v.is_nan());
+    /// assert!((v * 0.).is_nan());
+    /// ```
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite()
+    }
+
+    /// Checks if any component of the vector is `NaN`.
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.x.is_nan() || self.y.is_nan()
+    }
+
+    /// Checks if all components of two vectors differ by no more than `epsilon`.
+    #[inline]
+    pub fn abs_diff_eq(&self, other: &Self, epsilon: F) -> bool {
+        self.as_slice()
+            .iter()
+            .zip(other.as_slice())
+            .all(|(a, b)| (*a - *b).abs() <= epsilon)
+    }
+
+    /// Checks if all components of two vectors are equal within `epsilon`
+    /// or within `max_relative` of the larger component magnitude.
+    #[inline]
+    pub fn relative_eq(&self, other: &Self, epsilon: F, max_relative: F) -> bool {
+        self.as_slice()
+            .iter()
+            .zip(other.as_slice())
+            .all(|(a, b)| relative_eq(*a, *b, epsilon, max_relative))
+    }
+
+    /// Computes the reciprocal `1 / c` of every component.
+    /// Zero components produce infinities.
+    #[inline]
+    pub fn recip(&self) -> Self {
+        Self::new(self.x.recip(), self.y.recip())
+    }
+
+    /// Computes the centroid (arithmetic mean) of the points
+    /// or `None` if the slice is empty.
+    #[inline]
+    pub fn centroid(points: &[Self]) -> Option<Self> {
+        Self::mean(points.iter().copied())
+    }
+
+    /// Computes the arithmetic mean of the vectors
+    /// or `None` if the iterator is empty.
+    #[inline]
+    pub fn mean<I>(vectors: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        super::mean(vectors)
+    }
+
+    /// Computes the mean of the vectors weighted by the paired weights
+    /// or `None` if the weights sum up to zero.
+    #[inline]
+    pub fn weighted_mean<I>(vectors: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = (Self, F)>,
+    {
+        super::weighted_mean(vectors)
+    }
+
+    /// Converts the vector to the other floating point precision.
+    /// ```
+    /// # use ewq::vec::{Vec2d, Vec2f};
+    /// assert_eq!(Vec2f::new(0.1, 2.).cast::<f64>(), Vec2d::new(0.1f32 as f64, 2.));
+    /// ```
+    #[inline]
+    pub fn cast<T>(&self) -> Vec2<T>
+    where
+        T: Real,
+    {
+        Vec2::new(T::from(self.x).unwrap(), T::from(self.y).unwrap())
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<F> Vec2<F>
+where
+    F: Real,
+{
+    /// Samples uniformly distributed unit vector, i.e. a point on the unit circle.
+    /// ```
+    /// # use ewq::vec::Vec2d;
+    /// let mut rng = rand::thread_rng();
+    /// assert!((Vec2d::random_unit(&mut rng).magnitude() - 1.).abs() < 1e-12);
+    /// assert!(Vec2d::random_in_disk(&mut rng).magnitude() <= 1.);
+    /// ```
+    pub fn random_unit<R>(rng: &mut R) -> Self
+    where
+        R: rand::Rng + ?Sized,
+    {
+        let tau = F::from(std::f64::consts::TAU).unwrap();
+        Self::from_angle(tau * F::from(rng.gen::<f64>()).unwrap())
+    }
+
+    /// Samples uniformly distributed point inside of the unit disk.
+    pub fn random_in_disk<R>(rng: &mut R) -> Self
+    where
+        R: rand::Rng + ?Sized,
+    {
+        let r = F::from(rng.gen::<f64>()).unwrap().sqrt();
+        Self::random_unit(rng) * r
+    }
 }
 
 impl<F> Add for Vec2<F>
 where
-    F: Float,
+    F: Scalar,
 {
     type Output = Self;
 
@@ -150,7 +744,7 @@ where
 
 impl<F> AddAssign for Vec2<F>
 where
-    F: Float,
+    F: Scalar,
 {
     #[inline]
     fn add_assign(&mut self, rhs: Self) {
@@ -161,7 +755,7 @@ where
 
 impl<F> Sub for Vec2<F>
 where
-    F: Float,
+    F: Scalar,
 {
     type Output = Self;
 
@@ -176,7 +770,7 @@ where
 
 impl<F> SubAssign for Vec2<F>
 where
-    F: Float,
+    F: Scalar,
 {
     #[inline]
     fn sub_assign(&mut self, rhs: Self) {
@@ -187,7 +781,7 @@ where
 
 impl<F> Mul<F> for Vec2<F>
 where
-    F: Float,
+    F: Scalar,
 {
     type Output = Self;
 
@@ -202,7 +796,7 @@ where
 
 impl<F> MulAssign<F> for Vec2<F>
 where
-    F: Float,
+    F: Scalar,
 {
     #[inline]
     fn mul_assign(&mut self, rhs: F) {
@@ -213,7 +807,7 @@ where
 
 impl<F> Div<F> for Vec2<F>
 where
-    F: Float,
+    F: Scalar,
 {
     type Output = Self;
 
@@ -228,7 +822,7 @@ where
 
 impl<F> DivAssign<F> for Vec2<F>
 where
-    F: Float,
+    F: Scalar,
 {
     #[inline]
     fn div_assign(&mut self, rhs: F) {
@@ -239,7 +833,7 @@ where
 
 impl<F> Neg for Vec2<F>
 where
-    F: Float,
+    F: Scalar,
 {
     type Output = Self;
 
@@ -254,7 +848,7 @@ where
 
 impl<F> From<Complex<F>> for Vec2<F>
 where
-    F: Float,
+    F: Scalar,
 {
     #[inline]
     fn from(z: Complex<F>) -> Self {
@@ -264,3 +858,185 @@ where
         }
     }
 }
+
+impl<F> Index<usize> for Vec2<F>
+where
+    F: Scalar,
+{
+    type Output = F;
+
+    /// Gets the component by index.
+    /// # Panics
+    /// If index is `>1`.
+    /// ```
+    /// # use ewq::vec::Vec2f;
+    /// let mut v = Vec2f::new(1., 2.);
+    /// v[1] = 0.;
+    /// assert_eq!(v[0], 1.);
+    /// assert_eq!(v[1], 0.);
+    /// ```
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            _ => panic!("Index out of range"),
+        }
+    }
+}
+
+impl<F> IndexMut<usize> for Vec2<F>
+where
+    F: Scalar,
+{
+    /// Gets the mutable component by index.
+    /// # Panics
+    /// If index is `>1`.
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            _ => panic!("Index out of range"),
+        }
+    }
+}
+
+impl<F> AsRef<[F]> for Vec2<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn as_ref(&self) -> &[F] {
+        self.as_slice()
+    }
+}
+
+impl<F> AsMut<[F]> for Vec2<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn as_mut(&mut self) -> &mut [F] {
+        self.as_mut_slice()
+    }
+}
+
+impl<F> Sum for Vec2<F>
+where
+    F: Scalar,
+{
+    /// Sums the vectors.
+    /// ```
+    /// # use ewq::vec::Vec2f;
+    /// let points = [Vec2f::new(1., 2.), Vec2f::new(1., 2.)];
+    /// assert_eq!(points.iter().sum::<Vec2f>(), points[0] * 2.);
+    /// assert_eq!(points.into_iter().product::<Vec2f>(), Vec2f::new(1., 4.));
+    /// ```
+    #[inline]
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(F::zero(), F::zero()), |acc, v| acc + v)
+    }
+}
+
+impl<'a, F> Sum<&'a Vec2<F>> for Vec2<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl<F> Product for Vec2<F>
+where
+    F: Scalar,
+{
+    /// Multiplies the vectors component-wise.
+    #[inline]
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(F::one(), F::one()), |acc, v| Self {
+            x: acc.x * v.x,
+            y: acc.y * v.y,
+        })
+    }
+}
+
+impl<'a, F> Product<&'a Vec2<F>> for Vec2<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+impl<F> Display for Vec2<F>
+where
+    F: Scalar + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        super::fmt_tuple(f, &[self.x, self.y])
+    }
+}
+
+impl<F> FromStr for Vec2<F>
+where
+    F: Scalar + FromStr,
+{
+    type Err = ParseVecError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y] = super::parse_tuple(s)?;
+        Ok(Self::new(x, y))
+    }
+}
+
+macro_rules! vec2_scalar_mul {
+    ($($f:ty),+) => {$(
+        impl Mul<Vec2<$f>> for $f {
+            type Output = Vec2<$f>;
+
+            /// Multiplies the vector by the scalar.
+            /// ```
+            /// # use ewq::vec::Vec2;
+            #[doc = concat!("let v = Vec2::<", stringify!($f), ">::new(1., 2.);")]
+            /// assert_eq!(2. * v, v * 2.);
+            /// ```
+            #[inline]
+            fn mul(self, rhs: Vec2<$f>) -> Self::Output {
+                rhs * self
+            }
+        }
+    )+};
+}
+
+vec2_scalar_mul!(f32, f64);
+
+swizzle!(Vec2 {
+    xx => Vec2(x, x),
+    xy => Vec2(x, y),
+    yx => Vec2(y, x),
+    yy => Vec2(y, y),
+});
+
+/// Generates constants for the floating point vector types.
+macro_rules! vec2_consts {
+    ($($f:ty),+) => {$(
+        impl Vec2<$f> {
+            /// Vector with all components set to `0`.
+            pub const ZERO: Self = Self::new(0., 0.);
+            /// Vector with all components set to `1`.
+            pub const ONE: Self = Self::new(1., 1.);
+            /// Unit vector along `+X`.
+            pub const X: Self = Self::new(1., 0.);
+            /// Unit vector along `+Y`.
+            pub const Y: Self = Self::new(0., 1.);
+        }
+    )+};
+}
+
+vec2_consts!(f32, f64);
@@ -1,6 +1,10 @@
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
-use num_traits::Float;
-use super::Vec2;
+use std::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::iter::{Product, Sum};
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+use crate::scalar::{diff_of_products, max, min, mul_add, relative_eq};
+use crate::{Real, Scalar};
+use super::{ParseVecError, Vec2, Vec4};
 
 pub type Vec3f = Vec3<f32>;
 pub type Vec3d = Vec3<f64>;
@@ -10,7 +14,7 @@ pub type Vec3d = Vec3<f64>;
 #[repr(C)]
 pub struct Vec3<F>
 where
-    F: Float,
+    F: Scalar,
 {
     pub x: F,
     pub y: F,
@@ -19,7 +23,7 @@ where
 
 impl<F> Vec3<F>
 where
-    F: Float,
+    F: Scalar,
 {
     #[inline]
     pub fn yaw() -> Self {
@@ -51,7 +55,7 @@ where
 
 impl<F> Vec3<F>
 where
-    F: Float,
+    F: Scalar,
 {
     #[inline]
     pub fn one() -> Self {
@@ -128,11 +132,17 @@ where
 
 impl<F> Vec3<F>
 where
-    F: Float,
+    F: Scalar,
 {
-    /// Creates new vector.
+    /// Creates new vector. Usable in const contexts.
+    /// ```
+    /// # use ewq::vec::Vec3f;
+    /// static AXES: [Vec3f; 3] = [Vec3f::X, Vec3f::Y, Vec3f::new(0., 0., 1.)];
+    /// assert_eq!(AXES[2], Vec3f::Z);
+    /// assert_eq!(Vec3f::ZERO, Vec3f::zero());
+    /// ```
     #[inline(always)]
-    pub fn new(x: F, y: F, z: F) -> Self {
+    pub const fn new(x: F, y: F, z: F) -> Self {
         Self { x, y, z }
     }
 
@@ -147,6 +157,16 @@ where
         Self { x: v.x, y: v.y, z }
     }
 
+    /// Extends the vector to homogeneous coordinates with the given `w`.
+    /// ```
+    /// # use ewq::vec::{Vec3f, Vec4f};
+    /// assert_eq!(Vec3f::new(1., 2., 3.).extend(1.), Vec4f::new(1., 2., 3., 1.));
+    /// ```
+    #[inline]
+    pub fn extend(self, w: F) -> Vec4<F> {
+        Vec4::from_vec3(self, w)
+    }
+
     /// Splits vector into X and Y components.
     #[inline]
     pub fn split(&self) -> (F, F, F) {
@@ -188,9 +208,380 @@ where
     /// Computes dot product between two vectors.
     #[inline]
     pub fn dot(&self, other: Self) -> F {
-        self.x * other.x + self.y * other.y + self.z * other.z
+        mul_add(self.z, other.z, mul_add(self.y, other.y, self.x * other.x))
+    }
+
+    /// Scales all of the components by `factor`.
+    #[inline]
+    pub fn scale(&mut self, factor: F) {
+        self.x = self.x * factor;
+        self.y = self.y * factor;
+        self.z = self.z * factor;
+    }
+
+    /// Computes the squared magnitude of the vector.
+    #[inline]
+    pub fn sqrt_magnitude(&self) -> F {
+        self.x * self.x + self.y * self.y + self.z * self.z
+    }
+
+    /// Linearly interpolates between two vectors.
+    /// `t = 0` yields `self` and `t = 1` yields `other`.
+    /// ```
+    /// # use ewq::vec::Vec3;
+    /// let a = Vec3::new(-1., -1., -1.);
+    /// let b = Vec3::new(1., 1., 1.);
+    /// assert_eq!(a.lerp(b, 0.5), Vec3::new(0., 0., 0.));
+    /// assert_eq!(a.lerp(b, 0.), a);
+    /// assert_eq!(a.lerp(b, 1.), b);
+    /// ```
+    #[inline]
+    pub fn lerp(&self, other: Self, t: F) -> Self {
+        *self + (other - *self) * t
+    }
+
+    /// Linearly interpolates between two vectors with `t` clamped to `[0, 1]`,
+    /// so the result never leaves the segment between `self` and `other`.
+    /// ```
+    /// # use ewq::vec::Vec3f;
+    /// let a = Vec3f::new(0., 0., 0.);
+    /// let b = Vec3f::new(2., 4., 6.);
+    /// assert_eq!(a.lerp_clamped(b, 0.5), Vec3f::new(1., 2., 3.));
+    /// assert_eq!(a.lerp_clamped(b, 2.), b);
+    /// assert_eq!(a.lerp_clamped(b, -1.), a);
+    /// ```
+    #[inline]
+    pub fn lerp_clamped(&self, other: Self, t: F) -> Self {
+        let t = if t < F::zero() {
+            F::zero()
+        } else if t > F::one() {
+            F::one()
+        } else {
+            t
+        };
+        self.lerp(other, t)
+    }
+
+    /// Computes the interpolation factor `t` for which `a.lerp(b, t)` is the closest to `value`,
+    /// i.e. `0` at `a` and `1` at `b`. The result is not clamped.
+    /// `a` and `b` must not be equal.
+    /// ```
+    /// # use ewq::vec::Vec3f;
+    /// let a = Vec3f::new(0., 0., 0.);
+    /// let b = Vec3f::new(2., 0., 0.);
+    /// assert_eq!(Vec3f::inverse_lerp(a, b, Vec3f::new(0.5, 1., 0.)), 0.25);
+    /// assert_eq!(Vec3f::inverse_lerp(a, b, a.lerp(b, 0.75)), 0.75);
+    /// ```
+    #[inline]
+    pub fn inverse_lerp(a: Self, b: Self, value: Self) -> F {
+        let ab = b - a;
+        (value - a).dot(ab) / ab.dot(ab)
+    }
+
+    /// Linearly maps every component from the range `from` to the range `to`,
+    /// where ranges are given as `(start, end)` pairs of vectors.
+    /// ```
+    /// # use ewq::vec::Vec3f;
+    /// let uv = Vec3f::new(0.5, 0., 1.);
+    /// let from = (Vec3f::zero(), Vec3f::one());
+    /// let to = (Vec3f::new(-1., -1., -1.), Vec3f::new(1., 1., 1.));
+    /// assert_eq!(uv.remap(from, to), Vec3f::new(0., -1., 1.));
+    /// ```
+    #[inline]
+    pub fn remap(&self, from: (Self, Self), to: (Self, Self)) -> Self {
+        let r = |v: F, a: F, b: F, c: F, d: F| c + (v - a) * (d - c) / (b - a);
+        Self::new(
+            r(self.x, from.0.x, from.1.x, to.0.x, to.1.x),
+            r(self.y, from.0.y, from.1.y, to.0.y, to.1.y),
+            r(self.z, from.0.z, from.1.z, to.0.z, to.1.z),
+        )
+    }
+
+    /// Computes the cross product between two vectors.
+    /// ```
+    /// # use ewq::vec::Vec3f;
+    /// let a = Vec3f::new(1., 0., 0.);
+    /// let b = Vec3f::new(0., 1., 0.);
+    /// assert_eq!(a.cross(b), Vec3f::new(0., 0., 1.))
+    /// ```
+    #[inline]
+    pub fn cross(&self, other: Self) -> Self {
+        Self::new(
+            diff_of_products(self.y, other.z, self.z, other.y),
+            diff_of_products(self.z, other.x, self.x, other.z),
+            diff_of_products(self.x, other.y, self.y, other.x),
+        )
+    }
+
+    /// Computes the triple product between three vectors in order.
+    #[inline]
+    pub fn triple(&self, former: Self, latter: Self) -> F {
+        self.dot(former.cross(latter))
+    }
+
+    /// Computes the volume of the cuboid with a diagonal equals to the vector.
+    #[inline]
+    pub fn cuboid_volume(&self) -> F {
+        self.x * self.y * self.z
+    }
+
+    /// Computes barycentric coordinates of the point with respect to the triangle `a`, `b`, `c`.
+    /// Returns `None` if the triangle is degenerate.
+    /// The point is projected onto the plane of the triangle.
+    #[inline]
+    pub fn barycentric(&self, a: Self, b: Self, c: Self) -> Option<Self> {
+        let (ab, ac, ap) = (b - a, c - a, *self - a);
+        let (d00, d01, d11) = (ab.dot(ab), ab.dot(ac), ac.dot(ac));
+        let (d20, d21) = (ap.dot(ab), ap.dot(ac));
+        let denom = d00 * d11 - d01 * d01;
+        if denom == F::zero() {
+            return None;
+        }
+        let v = (d11 * d20 - d01 * d21) / denom;
+        let w = (d00 * d21 - d01 * d20) / denom;
+        Some(Self::new(F::one() - v - w, v, w))
+    }
+
+    /// Interpolates the attributes of the triangle vertices, treating the vector as barycentric weights.
+    /// ```
+    /// # use ewq::vec::Vec3f;
+    /// let weights = Vec3f::new(0.5, 0.25, 0.25);
+    /// assert_eq!(weights.interpolate_barycentric(2., 4., 8.), 4.);
+    /// ```
+    #[inline]
+    pub fn interpolate_barycentric<T>(&self, a: T, b: T, c: T) -> T
+    where
+        T: Add<Output = T> + Mul<F, Output = T>,
+    {
+        a * self.x + b * self.y + c * self.z
+    }
+
+    /// Reflects the vector off the surface with the given `normal`.
+    /// `normal` must be normalized.
+    /// ```
+    /// # use ewq::vec::Vec3;
+    /// let v = Vec3::new(1., 0., -1.);
+    /// assert_eq!(v.reflect(Vec3::top()), Vec3::new(1., 0., 1.));
+    /// ```
+    #[inline]
+    pub fn reflect(&self, normal: Self) -> Self {
+        *self - normal * ((F::one() + F::one()) * self.dot(normal))
+    }
+
+    /// Projects the vector onto `other`.
+    /// ```
+    /// # use ewq::vec::Vec3;
+    /// let v = Vec3::new(3., 4., 5.);
+    /// let onto = Vec3::new(0., 0., 2.);
+    /// assert_eq!(v.project_onto(onto), Vec3::new(0., 0., 5.));
+    /// assert_eq!(v.project_onto(onto) + v.reject_from(onto), v);
+    /// ```
+    #[inline]
+    pub fn project_onto(&self, other: Self) -> Self {
+        other * (self.dot(other) / other.sqrt_magnitude())
+    }
+
+    /// Projects the vector onto `other`, which must be normalized.
+    #[inline]
+    pub fn project_onto_normalized(&self, other: Self) -> Self {
+        other * self.dot(other)
+    }
+
+    /// Returns the component of the vector perpendicular to `other`.
+    #[inline]
+    pub fn reject_from(&self, other: Self) -> Self {
+        *self - self.project_onto(other)
+    }
+
+    /// Returns the component of the vector perpendicular to `other`, which must be normalized.
+    #[inline]
+    pub fn reject_from_normalized(&self, other: Self) -> Self {
+        *self - self.project_onto_normalized(other)
+    }
+
+    /// Performs cubic Hermite interpolation from `self` with tangent `tangent`
+    /// to `other` with tangent `other_tangent`.
+    /// ```
+    /// # use ewq::vec::Vec3f;
+    /// let a = Vec3f::new(0., 0., 0.);
+    /// let b = Vec3f::new(2., 2., 2.);
+    /// let m = Vec3f::new(0., 0., 0.);
+    /// assert_eq!(a.hermite(m, b, m, 0.5), a.smoothstep(b, 0.5));
+    /// assert_eq!(a.hermite(m, b, m, 1.), b);
+    /// ```
+    #[inline]
+    pub fn hermite(&self, tangent: Self, other: Self, other_tangent: Self, t: F) -> Self {
+        let two = F::one() + F::one();
+        let three = two + F::one();
+        let (t2, t3) = (t * t, t * t * t);
+        *self * (two * t3 - three * t2 + F::one())
+            + tangent * (t3 - two * t2 + t)
+            + other * (three * t2 - two * t3)
+            + other_tangent * (t3 - t2)
+    }
+
+    /// Views the components of the vector as a slice.
+    /// ```
+    /// # use ewq::vec::Vec3f;
+    /// let mut v = Vec3f::new(1., 2., 3.);
+    /// assert_eq!(v.as_slice(), &[1., 2., 3.]);
+    /// v.as_mut_slice()[0] = 0.;
+    /// assert_eq!(v.x, 0.);
+    /// ```
+    #[inline]
+    pub fn as_slice(&self) -> &[F] {
+        // SAFETY: `#[repr(C)]` struct of 3 `F` fields has the same layout as `[F; 3]`.
+        unsafe { std::slice::from_raw_parts(self as *const Self as *const F, 3) }
+    }
+
+    /// Views the components of the vector as a mutable slice.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [F] {
+        // SAFETY: `#[repr(C)]` struct of 3 `F` fields has the same layout as `[F; 3]`.
+        unsafe { std::slice::from_raw_parts_mut(self as *mut Self as *mut F, 3) }
+    }
+
+    /// Returns an iterator over the components of the vector.
+    #[inline]
+    pub fn iter(&self) -> std::slice::Iter<'_, F> {
+        self.as_slice().iter()
+    }
+
+    /// Computes the sum of all components.
+    /// ```
+    /// # use ewq::vec::Vec3f;
+    /// let v = Vec3f::new(1., 2., 3.);
+    /// assert_eq!(v.element_sum(), 6.);
+    /// assert_eq!(v.element_product(), 6.);
+    /// ```
+    #[inline]
+    pub fn element_sum(&self) -> F {
+        self.x + self.y + self.z
+    }
+
+    /// Computes the product of all components.
+    #[inline]
+    pub fn element_product(&self) -> F {
+        self.x * self.y * self.z
+    }
+
+    /// Returns the index of the smallest component of the vector.
+    #[inline]
+    pub fn min_axis(&self) -> usize {
+        (1..3).fold(0, |min, i| if self[i] < self[min] { i } else { min })
+    }
+
+    /// Returns the index of the largest component of the vector.
+    #[inline]
+    pub fn max_axis(&self) -> usize {
+        (1..3).fold(0, |max, i| if self[i] > self[max] { i } else { max })
+    }
+
+    /// Returns component-wise minimum of two vectors.
+    /// ```
+    /// # use ewq::vec::Vec3;
+    /// let a = Vec3::new(1i64, 5, 3);
+    /// assert_eq!(a.min(Vec3::new(2, 2, 2)), Vec3::new(1, 2, 2));
+    /// assert_eq!(a.max(Vec3::new(2, 2, 2)), Vec3::new(2, 5, 3));
+    /// ```
+    #[inline]
+    pub fn min(&self, other: Self) -> Self {
+        Self {
+            x: min(self.x, other.x),
+            y: min(self.y, other.y),
+            z: min(self.z, other.z),
+        }
+    }
+
+    /// Returns component-wise maximum of two vectors.
+    #[inline]
+    pub fn max(&self, other: Self) -> Self {
+        Self {
+            x: max(self.x, other.x),
+            y: max(self.y, other.y),
+            z: max(self.z, other.z),
+        }
+    }
+
+    /// Clamps each component of the vector between the corresponding components of `lo` and `hi`.
+    /// ```
+    /// # use ewq::vec::Vec3;
+    /// let v = Vec3::new(3., -1., 2.);
+    /// let lo = Vec3::new(0., 0., 0.);
+    /// let hi = Vec3::new(2., 2., 2.);
+    /// assert_eq!(v.clamp(lo, hi), Vec3::new(2., 0., 2.));
+    /// ```
+    #[inline]
+    pub fn clamp(&self, lo: Self, hi: Self) -> Self {
+        Self {
+            x: min(max(self.x, lo.x), hi.x),
+            y: min(max(self.y, lo.y), hi.y),
+            z: min(max(self.z, lo.z), hi.z),
+        }
+    }
+
+    /// Returns the smallest component of the vector.
+    /// ```
+    /// # use ewq::vec::Vec3f;
+    /// let v = Vec3f::new(3., -1., 2.);
+    /// assert_eq!(v.min_element(), -1.);
+    /// assert_eq!(v.max_element(), 3.);
+    /// assert_eq!(v.min_axis(), 1);
+    /// assert_eq!(v.max_axis(), 0);
+    /// ```
+    #[inline]
+    pub fn min_element(&self) -> F {
+        min(min(self.x, self.y), self.z)
+    }
+
+    /// Returns the largest component of the vector.
+    #[inline]
+    pub fn max_element(&self) -> F {
+        max(max(self.x, self.y), self.z)
     }
 
+    /// Computes the least nonnegative remainder of every component
+    /// divided by the corresponding component of `rhs`.
+    /// ```
+    /// # use ewq::vec::Vec3;
+    /// let v = Vec3::new(-7i32, 7, -7);
+    /// assert_eq!(v.rem_euclid(Vec3::new(3, 3, -3)), Vec3::new(2, 1, 2));
+    /// ```
+    #[inline]
+    pub fn rem_euclid(&self, rhs: Self) -> Self {
+        let r = |a: F, b: F| {
+            let r = a % b;
+            if r >= F::zero() {
+                return r;
+            }
+            // Tiny negative remainders can round up to `|b|` itself.
+            let r = if b < F::zero() { r - b } else { r + b };
+            if r == b || r == -b {
+                F::zero()
+            } else {
+                r
+            }
+        };
+        Self::new(r(self.x, rhs.x), r(self.y, rhs.y), r(self.z, rhs.z))
+    }
+
+    /// Wraps every component into the range `[min, max)`,
+    /// e.g. positions in a tiling world or texture coordinates.
+    /// ```
+    /// # use ewq::vec::Vec3f;
+    /// let v = Vec3f::new(-1e-9, 2.5, -0.5);
+    /// assert_eq!(v.wrap(Vec3f::ZERO, Vec3f::ONE), Vec3f::new(0., 0.5, 0.5));
+    /// ```
+    #[inline]
+    pub fn wrap(&self, min: Self, max: Self) -> Self {
+        min + (*self - min).rem_euclid(max - min)
+    }
+}
+
+impl<F> Vec3<F>
+where
+    F: Real,
+{
     /// Computes dot product between two normalized copies of the vectors.
     #[inline]
     pub fn dot_normalized(&self, other: Self) -> F {
@@ -203,29 +594,39 @@ where
         (other - *self).magnitude()
     }
 
-    /// Scales all of the components by `factor`.
+    /// Computes `self * a + b` component-wise,
+    /// rounding once for `f32` and `f64` if the `fma` feature is enabled.
+    /// ```
+    /// # use ewq::vec::Vec3f;
+    /// let v = Vec3f::new(1., 2., 3.);
+    /// assert_eq!(v.mul_add(Vec3f::new(2., 2., 2.), Vec3f::one()), Vec3f::new(3., 5., 7.));
+    /// ```
     #[inline]
-    pub fn scale(&mut self, factor: F) {
-        self.x = self.x * factor;
-        self.y = self.y * factor;
-        self.z = self.z * factor;
+    pub fn mul_add(&self, a: Self, b: Self) -> Self {
+        Self::new(
+            mul_add(self.x, a.x, b.x),
+            mul_add(self.y, a.y, b.y),
+            mul_add(self.z, a.z, b.z),
+        )
     }
 
     /// Computes the magnitude of the vector.
     #[inline]
     pub fn magnitude(&self) -> F {
-        F::sqrt(self.sqrt_magnitude())
-    }
-
-    /// Computes the squared magnitude of the vector.
-    #[inline]
-    pub fn sqrt_magnitude(&self) -> F {
-        self.x * self.x + self.y * self.y + self.z * self.z
+        F::sqrt(mul_add(
+            self.z,
+            self.z,
+            mul_add(self.y, self.y, self.x * self.x),
+        ))
     }
 
     /// Returns the normalized version of the vector.
     #[inline]
     pub fn normalized(&self) -> Self {
+        #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+        if let Some(r) = crate::simd::vec3_normalized(*self) {
+            return r;
+        }
         let l = self.magnitude();
         Self {
             x: self.x / l,
@@ -234,6 +635,43 @@ where
         }
     }
 
+    /// Returns the normalized version of the vector
+    /// or `None` if its magnitude is too close to zero.
+    /// ```
+    /// # use ewq::vec::Vec3f;
+    /// assert_eq!(Vec3f::default().try_normalize(), None);
+    /// assert_eq!(Vec3f::new(0., 3., 4.).try_normalize(), Some(Vec3f::new(0., 3., 4.) / 5.));
+    /// ```
+    #[inline]
+    pub fn try_normalize(&self) -> Option<Self> {
+        let l = self.magnitude();
+        if l > F::epsilon() {
+            Some(*self / l)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the normalized version of the vector
+    /// or zero vector if its magnitude is too close to zero.
+    #[inline]
+    pub fn normalize_or_zero(&self) -> Self {
+        self.normalize_or(Self::zero())
+    }
+
+    /// Returns the normalized version of the vector
+    /// or `fallback` if its magnitude is too close to zero.
+    /// ```
+    /// # use ewq::vec::Vec3f;
+    /// let fallback = Vec3f::new(1., 0., 0.);
+    /// assert_eq!(Vec3f::default().normalize_or(fallback), fallback);
+    /// assert_eq!(Vec3f::default().normalize_or_zero(), Vec3f::default());
+    /// ```
+    #[inline]
+    pub fn normalize_or(&self, fallback: Self) -> Self {
+        self.try_normalize().unwrap_or(fallback)
+    }
+
     /// Normalizes the vector, preserving direction but reducing its magnitude to `1`.
     #[inline]
     pub fn normalize(&mut self) {
@@ -255,50 +693,366 @@ where
         F::acos(self.dot_normalized(other))
     }
 
-    /// Linearly interpolates between two vectors.
+    /// Computes the signed angle from `self` to `other` in `(-π, π]` range.
+    /// The angle is positive when the rotation is counterclockwise looking against `axis`.
+    /// ```
+    /// # use ewq::vec::Vec3f;
+    /// let a = Vec3f::forward();
+    /// let b = Vec3f::new(1., 1., 0.);
+    /// assert_eq!(a.signed_angle_to(b, Vec3f::top()), std::f32::consts::FRAC_PI_4);
+    /// assert_eq!(a.signed_angle_to(b, Vec3f::bottom()), -std::f32::consts::FRAC_PI_4);
+    /// ```
+    #[inline]
+    pub fn signed_angle_to(&self, other: Self, axis: Self) -> F {
+        let cross = self.cross(other);
+        let sin = if cross.dot(axis) < F::zero() {
+            -cross.magnitude()
+        } else {
+            cross.magnitude()
+        };
+        F::atan2(sin, self.dot(other))
+    }
+
+    /// Creates new unit direction vector from `yaw` (rotation around `+Z`)
+    /// and `pitch` (rotation around `-Y`) angles in radians,
+    /// so zero angles give [`Vec3::forward`] and positive pitch looks up.
+    /// ```
+    /// # use ewq::vec::Vec3d;
+    /// let v = Vec3d::from_yaw_pitch(std::f64::consts::FRAC_PI_2, 0.);
+    /// assert!((v - Vec3d::left()).magnitude() < 1e-12);
+    /// let (yaw, pitch) = Vec3d::from_yaw_pitch(0.5, -0.25).to_yaw_pitch();
+    /// assert!((yaw - 0.5).abs() < 1e-12 && (pitch + 0.25).abs() < 1e-12);
+    /// ```
+    #[inline]
+    pub fn from_yaw_pitch(yaw: F, pitch: F) -> Self {
+        let (sin_yaw, cos_yaw) = yaw.sin_cos();
+        let (sin_pitch, cos_pitch) = pitch.sin_cos();
+        Self::new(cos_pitch * cos_yaw, cos_pitch * sin_yaw, sin_pitch)
+    }
+
+    /// Recovers `yaw` and `pitch` angles of the direction vector, see [`Vec3::from_yaw_pitch`].
+    /// Vector is not required to be normalized.
+    #[inline]
+    pub fn to_yaw_pitch(&self) -> (F, F) {
+        (
+            F::atan2(self.y, self.x),
+            F::atan2(self.z, self.x.hypot(self.y)),
+        )
+    }
+
+    /// Returns some unit vector perpendicular to the vector.
+    /// The vector must be non-zero, but is not required to be normalized.
+    /// ```
+    /// # use ewq::vec::Vec3d;
+    /// let v = Vec3d::new(1e-9, 2., 3.);
+    /// let o = v.any_orthogonal();
+    /// assert!(v.dot(o).abs() < 1e-12);
+    /// assert!((o.magnitude() - 1.).abs() < 1e-12);
+    /// ```
+    #[inline]
+    pub fn any_orthogonal(&self) -> Self {
+        let (x, y, z) = (self.x.abs(), self.y.abs(), self.z.abs());
+        let other = if x < y && x < z {
+            Self::forward()
+        } else if y < z {
+            Self::left()
+        } else {
+            Self::top()
+        };
+        self.cross(other).normalized()
+    }
+
+    /// Refracts the vector through the surface with the given `normal`,
+    /// where `eta` is the ratio of the refractive indices.
+    /// Both the vector and `normal` must be normalized.
+    /// Returns `None` in case of total internal reflection.
     /// ```
     /// # use ewq::vec::Vec3;
-    /// let a = Vec3::new(-1., -1., -1.);
-    /// let b = Vec3::new(1., 1., 1.);
-    /// assert_eq!(a.lerp(b, 0.5), Vec3::new(0., 0., 0.));
+    /// let v = Vec3::new(1., 0., -1.).normalized();
+    /// assert_eq!(v.refract(Vec3::top(), 1.), Some(v));
+    /// assert_eq!(v.refract(Vec3::top(), 1.5), None);
     /// ```
     #[inline]
-    pub fn lerp(&self, other: Self, t: F) -> Self {
-        *self + (other - *self) * (F::one() - t)
+    pub fn refract(&self, normal: Self, eta: F) -> Option<Self> {
+        let cos = self.dot(normal);
+        let k = F::one() - eta * eta * (F::one() - cos * cos);
+        if k < F::zero() {
+            None
+        } else {
+            Some(*self * eta - normal * (eta * cos + k.sqrt()))
+        }
     }
 
-    /// Computes the cross product between two vectors.
+    /// Returns the vector with its magnitude limited to `max`.
     /// ```
     /// # use ewq::vec::Vec3f;
-    /// let a = Vec3f::new(1., 0., 0.);
-    /// let b = Vec3f::new(0., 1., 0.);
-    /// assert_eq!(a.cross(b), Vec3f::new(0., 0., 1.))
+    /// let v = Vec3f::new(0., 3., 4.);
+    /// assert_eq!(v.clamp_magnitude(1.), Vec3f::new(0., 0.6, 0.8));
+    /// assert_eq!(v.clamp_magnitude(10.), v);
     /// ```
     #[inline]
-    pub fn cross(&self, other: Self) -> Self {
-        Self::new(
-            self.y * other.z - self.z * other.y,
-            self.z * other.x - self.x * other.z,
-            self.x * other.y - self.y * other.x,
-        )
+    pub fn clamp_magnitude(&self, max: F) -> Self {
+        let l = self.magnitude();
+        if l > max {
+            *self * (max / l)
+        } else {
+            *self
+        }
     }
 
-    /// Computes the triple product between three vectors in order.
+    /// Returns the vector with the same direction and magnitude of `magnitude`.
+    /// Zero vector is returned as is.
+    /// ```
+    /// # use ewq::vec::Vec3f;
+    /// assert_eq!(Vec3f::new(0., 3., 4.).with_magnitude(10.), Vec3f::new(0., 6., 8.));
+    /// ```
     #[inline]
-    pub fn triple(&self, former: Self, latter: Self) -> F {
-        self.dot(former.cross(latter))
+    pub fn with_magnitude(&self, magnitude: F) -> Self {
+        self.normalize_or_zero() * magnitude
     }
 
-    /// Computes the volume of the cuboid with a diagonal equals to the vector.
+    /// Moves the vector toward `target` by no more than `max_delta` distance.
+    /// ```
+    /// # use ewq::vec::Vec3f;
+    /// let v = Vec3f::new(0., 0., 0.);
+    /// let target = Vec3f::new(0., 0., 10.);
+    /// assert_eq!(v.move_toward(target, 3.), Vec3f::new(0., 0., 3.));
+    /// assert_eq!(v.move_toward(target, 20.), target);
+    /// ```
     #[inline]
-    pub fn cuboid_volume(&self) -> F {
-        self.x * self.y * self.z
+    pub fn move_toward(&self, target: Self, max_delta: F) -> Self {
+        let delta = target - *self;
+        let l = delta.magnitude();
+        if l <= max_delta || l <= F::epsilon() {
+            target
+        } else {
+            *self + delta * (max_delta / l)
+        }
+    }
+
+    /// Interpolates between two vectors with smoothstep easing,
+    /// `t` is clamped to `[0, 1]` range.
+    /// ```
+    /// # use ewq::vec::Vec3f;
+    /// let a = Vec3f::new(0., 0., 0.);
+    /// let b = Vec3f::new(2., 2., 2.);
+    /// assert_eq!(a.smoothstep(b, 0.), a);
+    /// assert_eq!(a.smoothstep(b, 0.25), Vec3f::new(0.3125, 0.3125, 0.3125));
+    /// assert_eq!(a.smoothstep(b, 2.), b);
+    /// ```
+    #[inline]
+    pub fn smoothstep(&self, other: Self, t: F) -> Self {
+        let t = t.max(F::zero()).min(F::one());
+        let three = F::one() + F::one() + F::one();
+        *self + (other - *self) * (t * t * (three - (t + t)))
+    }
+
+    /// Linearly interpolates between two direction vectors and normalizes the result.
+    #[inline]
+    pub fn nlerp(&self, other: Self, t: F) -> Self {
+        (*self + (other - *self) * t).normalized()
+    }
+
+    /// Spherically interpolates between two normalized direction vectors along the great circle.
+    /// For opposite directions an arbitrary perpendicular great circle is used.
+    /// ```
+    /// # use ewq::vec::Vec3d;
+    /// let a = Vec3d::forward();
+    /// let b = Vec3d::left();
+    /// let mid = a.slerp(b, 0.5);
+    /// assert!((mid - Vec3d::new(1., 1., 0.).normalized()).magnitude() < 1e-12);
+    /// assert!((a.slerp(-a, 0.5).magnitude() - 1.).abs() < 1e-12);
+    /// ```
+    #[inline]
+    pub fn slerp(&self, other: Self, t: F) -> Self {
+        let cos = self.dot(other).max(-F::one()).min(F::one());
+        if cos >= F::one() - F::epsilon() {
+            return self.nlerp(other, t);
+        }
+        if cos <= F::epsilon() - F::one() {
+            let (sin, cos) = (t * F::from(std::f64::consts::PI).unwrap()).sin_cos();
+            return *self * cos + self.any_orthogonal() * sin;
+        }
+
+        let angle = cos.acos();
+        let sin = angle.sin();
+        *self * (((F::one() - t) * angle).sin() / sin) + other * ((t * angle).sin() / sin)
+    }
+
+    /// Checks if all components of the vector are finite.
+    /// ```
+    /// # use ewq::vec::Vec3f;
+    /// let v = Vec3f::new(1., 1., f32::INFINITY);
+    /// assert!(!v.is_finite());
+    /// assert!(!v.is_nan());
+    /// assert!((v * 0.).is_nan());
+    /// ```
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+    }
+
+    /// Checks if any component of the vector is `NaN`.
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.x.is_nan() || self.y.is_nan() || self.z.is_nan()
+    }
+
+    /// Checks if all components of two vectors differ by no more than `epsilon`.
+    /// ```
+    /// # use ewq::vec::Vec3d;
+    /// let a = Vec3d::new(0.1 + 0.2, 1., 1e6);
+    /// let b = Vec3d::new(0.3, 1., 1e6 + 1e-4);
+    /// assert!(a != b);
+    /// assert!(!a.abs_diff_eq(&b, 1e-12));
+    /// assert!(a.relative_eq(&b, 1e-12, 1e-9));
+    /// ```
+    #[inline]
+    pub fn abs_diff_eq(&self, other: &Self, epsilon: F) -> bool {
+        self.as_slice()
+            .iter()
+            .zip(other.as_slice())
+            .all(|(a, b)| (*a - *b).abs() <= epsilon)
+    }
+
+    /// Checks if all components of two vectors are equal within `epsilon`
+    /// or within `max_relative` of the larger component magnitude.
+    #[inline]
+    pub fn relative_eq(&self, other: &Self, epsilon: F, max_relative: F) -> bool {
+        self.as_slice()
+            .iter()
+            .zip(other.as_slice())
+            .all(|(a, b)| relative_eq(*a, *b, epsilon, max_relative))
+    }
+
+    /// Computes the reciprocal `1 / c` of every component.
+    /// Zero components produce infinities.
+    /// ```
+    /// # use ewq::vec::Vec3f;
+    /// let v = Vec3f::new(2., -4., 0.);
+    /// assert_eq!(v.recip(), Vec3f::new(0.5, -0.25, f32::INFINITY));
+    /// ```
+    #[inline]
+    pub fn recip(&self) -> Self {
+        Self::new(self.x.recip(), self.y.recip(), self.z.recip())
+    }
+
+    /// Computes the centroid (arithmetic mean) of the points
+    /// or `None` if the slice is empty.
+    /// ```
+    /// # use ewq::vec::Vec3f;
+    /// let points = [Vec3f::new(0., 0., 0.), Vec3f::new(2., 0., 0.), Vec3f::new(1., 3., 0.)];
+    /// assert_eq!(Vec3f::centroid(&points), Some(Vec3f::new(1., 1., 0.)));
+    /// assert_eq!(Vec3f::centroid(&[]), None);
+    /// ```
+    #[inline]
+    pub fn centroid(points: &[Self]) -> Option<Self> {
+        Self::mean(points.iter().copied())
+    }
+
+    /// Computes the arithmetic mean of the vectors
+    /// or `None` if the iterator is empty.
+    #[inline]
+    pub fn mean<I>(vectors: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        super::mean(vectors)
+    }
+
+    /// Computes the mean of the vectors weighted by the paired weights
+    /// or `None` if the weights sum up to zero.
+    /// ```
+    /// # use ewq::vec::Vec3f;
+    /// let a = Vec3f::new(0., 0., 0.);
+    /// let b = Vec3f::new(4., 0., 0.);
+    /// assert_eq!(Vec3f::weighted_mean([(a, 3.), (b, 1.)]), Some(Vec3f::new(1., 0., 0.)));
+    /// assert_eq!(Vec3f::weighted_mean([(a, 0.), (b, 0.)]), None);
+    /// ```
+    #[inline]
+    pub fn weighted_mean<I>(vectors: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = (Self, F)>,
+    {
+        super::weighted_mean(vectors)
+    }
+
+    /// Converts the vector to the other floating point precision.
+    /// ```
+    /// # use ewq::vec::{Vec3d, Vec3f};
+    /// assert_eq!(Vec3d::new(1.5, 2., 3.).cast::<f32>(), Vec3f::new(1.5, 2., 3.));
+    /// ```
+    #[inline]
+    pub fn cast<T>(&self) -> Vec3<T>
+    where
+        T: Real,
+    {
+        Vec3::new(
+            T::from(self.x).unwrap(),
+            T::from(self.y).unwrap(),
+            T::from(self.z).unwrap(),
+        )
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<F> Vec3<F>
+where
+    F: Real,
+{
+    /// Samples uniformly distributed unit vector, i.e. a point on the unit sphere.
+    /// ```
+    /// # use ewq::vec::Vec3d;
+    /// let mut rng = rand::thread_rng();
+    /// assert!((Vec3d::random_unit(&mut rng).magnitude() - 1.).abs() < 1e-12);
+    /// assert!(Vec3d::random_in_ball(&mut rng).magnitude() <= 1.);
+    /// let d = Vec3d::random_cosine_hemisphere(&mut rng, Vec3d::left());
+    /// assert!(d.y >= 0. && (d.magnitude() - 1.).abs() < 1e-12);
+    /// ```
+    pub fn random_unit<R>(rng: &mut R) -> Self
+    where
+        R: rand::Rng + ?Sized,
+    {
+        let tau = F::from(std::f64::consts::TAU).unwrap();
+        let mut sample = || F::from(rng.gen::<f64>()).unwrap();
+        let (u1, u2) = (sample(), sample());
+
+        let z = F::one() - (u1 + u1);
+        let (sin, cos) = (tau * u2).sin_cos();
+        let r = (F::one() - z * z).max(F::zero()).sqrt();
+        Self::new(r * cos, r * sin, z)
+    }
+
+    /// Samples uniformly distributed point inside of the unit ball.
+    pub fn random_in_ball<R>(rng: &mut R) -> Self
+    where
+        R: rand::Rng + ?Sized,
+    {
+        let r = F::from(rng.gen::<f64>()).unwrap().cbrt();
+        Self::random_unit(rng) * r
+    }
+
+    /// Samples cosine-weighted unit direction in the hemisphere around `normal`,
+    /// which must be normalized.
+    pub fn random_cosine_hemisphere<R>(rng: &mut R, normal: Self) -> Self
+    where
+        R: rand::Rng + ?Sized,
+    {
+        let tau = F::from(std::f64::consts::TAU).unwrap();
+        let mut sample = || F::from(rng.gen::<f64>()).unwrap();
+        let (u1, u2) = (sample(), sample());
+
+        let (sin, cos) = (tau * u2).sin_cos();
+        let r = u1.sqrt();
+        let tangent = normal.any_orthogonal();
+        let bitangent = normal.cross(tangent);
+        tangent * (r * cos) + bitangent * (r * sin) + normal * (F::one() - u1).sqrt()
     }
 }
 
 impl<F> Add for Vec3<F>
 where
-    F: Float,
+    F: Scalar,
 {
     type Output = Self;
 
@@ -314,7 +1068,7 @@ where
 
 impl<F> AddAssign for Vec3<F>
 where
-    F: Float,
+    F: Scalar,
 {
     #[inline]
     fn add_assign(&mut self, rhs: Self) {
@@ -326,7 +1080,7 @@ where
 
 impl<F> Sub for Vec3<F>
 where
-    F: Float,
+    F: Scalar,
 {
     type Output = Self;
 
@@ -342,7 +1096,7 @@ where
 
 impl<F> SubAssign for Vec3<F>
 where
-    F: Float,
+    F: Scalar,
 {
     #[inline]
     fn sub_assign(&mut self, rhs: Self) {
@@ -354,7 +1108,7 @@ where
 
 impl<F> Mul<F> for Vec3<F>
 where
-    F: Float,
+    F: Scalar,
 {
     type Output = Self;
 
@@ -370,7 +1124,7 @@ where
 
 impl<F> MulAssign<F> for Vec3<F>
 where
-    F: Float,
+    F: Scalar,
 {
     #[inline]
     fn mul_assign(&mut self, rhs: F) {
@@ -382,7 +1136,7 @@ where
 
 impl<F> Div<F> for Vec3<F>
 where
-    F: Float,
+    F: Scalar,
 {
     type Output = Self;
 
@@ -398,7 +1152,7 @@ where
 
 impl<F> DivAssign<F> for Vec3<F>
 where
-    F: Float,
+    F: Scalar,
 {
     #[inline]
     fn div_assign(&mut self, rhs: F) {
@@ -410,7 +1164,7 @@ where
 
 impl<F> Neg for Vec3<F>
 where
-    F: Float,
+    F: Scalar,
 {
     type Output = Self;
 
@@ -423,3 +1177,242 @@ where
         }
     }
 }
+
+impl<F> Index<usize> for Vec3<F>
+where
+    F: Scalar,
+{
+    type Output = F;
+
+    /// Gets the component by index.
+    /// # Panics
+    /// If index is `>2`.
+    /// ```
+    /// # use ewq::vec::Vec3f;
+    /// let mut v = Vec3f::new(1., 2., 3.);
+    /// v[2] = 0.;
+    /// assert_eq!(v[0], 1.);
+    /// assert_eq!(v[2], 0.);
+    /// ```
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("Index out of range"),
+        }
+    }
+}
+
+impl<F> IndexMut<usize> for Vec3<F>
+where
+    F: Scalar,
+{
+    /// Gets the mutable component by index.
+    /// # Panics
+    /// If index is `>2`.
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("Index out of range"),
+        }
+    }
+}
+
+impl<F> AsRef<[F]> for Vec3<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn as_ref(&self) -> &[F] {
+        self.as_slice()
+    }
+}
+
+impl<F> AsMut<[F]> for Vec3<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn as_mut(&mut self) -> &mut [F] {
+        self.as_mut_slice()
+    }
+}
+
+impl<F> Sum for Vec3<F>
+where
+    F: Scalar,
+{
+    /// Sums the vectors.
+    /// ```
+    /// # use ewq::vec::Vec3f;
+    /// let points = [Vec3f::new(1., 2., 3.), Vec3f::new(1., 2., 3.)];
+    /// assert_eq!(points.iter().sum::<Vec3f>(), points[0] * 2.);
+    /// assert_eq!(points.into_iter().product::<Vec3f>(), Vec3f::new(1., 4., 9.));
+    /// ```
+    #[inline]
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(F::zero(), F::zero(), F::zero()), |acc, v| acc + v)
+    }
+}
+
+impl<'a, F> Sum<&'a Vec3<F>> for Vec3<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl<F> Product for Vec3<F>
+where
+    F: Scalar,
+{
+    /// Multiplies the vectors component-wise.
+    #[inline]
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(F::one(), F::one(), F::one()), |acc, v| Self {
+            x: acc.x * v.x,
+            y: acc.y * v.y,
+            z: acc.z * v.z,
+        })
+    }
+}
+
+impl<'a, F> Product<&'a Vec3<F>> for Vec3<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+impl<F> Display for Vec3<F>
+where
+    F: Scalar + Display,
+{
+    /// Formats the vector as a tuple, i.e. `(1, 2, 3)`.
+    /// ```
+    /// # use ewq::vec::Vec3f;
+    /// let v = Vec3f::new(1., 2.5, -3.);
+    /// assert_eq!(v.to_string(), "(1, 2.5, -3)");
+    /// assert_eq!(format!("{:.2}", v), "(1.00, 2.50, -3.00)");
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        super::fmt_tuple(f, &[self.x, self.y, self.z])
+    }
+}
+
+impl<F> FromStr for Vec3<F>
+where
+    F: Scalar + FromStr,
+{
+    type Err = ParseVecError;
+
+    /// Parses comma separated components, optionally enclosed in parentheses.
+    /// ```
+    /// # use ewq::vec::{ParseVecError, Vec3f};
+    /// let v: Vec3f = "(1, 2.5, -3)".parse().unwrap();
+    /// assert_eq!(v, Vec3f::new(1., 2.5, -3.));
+    /// assert_eq!("1,2.5,-3".parse(), Ok(v));
+    /// assert_eq!(v.to_string().parse(), Ok(v));
+    /// assert_eq!(
+    ///     "1, 2".parse::<Vec3f>(),
+    ///     Err(ParseVecError::ComponentCount { expected: 3, found: 2 })
+    /// );
+    /// assert_eq!("1, a, 3".parse::<Vec3f>(), Err(ParseVecError::InvalidComponent(1)));
+    /// ```
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y, z] = super::parse_tuple(s)?;
+        Ok(Self::new(x, y, z))
+    }
+}
+
+macro_rules! vec3_scalar_mul {
+    ($($f:ty),+) => {$(
+        impl Mul<Vec3<$f>> for $f {
+            type Output = Vec3<$f>;
+
+            /// Multiplies the vector by the scalar.
+            /// ```
+            /// # use ewq::vec::Vec3;
+            #[doc = concat!("let v = Vec3::<", stringify!($f), ">::new(1., 2., 3.);")]
+            /// assert_eq!(2. * v, v * 2.);
+            /// ```
+            #[inline]
+            fn mul(self, rhs: Vec3<$f>) -> Self::Output {
+                rhs * self
+            }
+        }
+    )+};
+}
+
+vec3_scalar_mul!(f32, f64);
+
+swizzle!(Vec3 {
+    xx => Vec2(x, x),
+    xy => Vec2(x, y),
+    xz => Vec2(x, z),
+    yx => Vec2(y, x),
+    yy => Vec2(y, y),
+    yz => Vec2(y, z),
+    zx => Vec2(z, x),
+    zy => Vec2(z, y),
+    zz => Vec2(z, z),
+    xxx => Vec3(x, x, x),
+    xxy => Vec3(x, x, y),
+    xxz => Vec3(x, x, z),
+    xyx => Vec3(x, y, x),
+    xyy => Vec3(x, y, y),
+    xyz => Vec3(x, y, z),
+    xzx => Vec3(x, z, x),
+    xzy => Vec3(x, z, y),
+    xzz => Vec3(x, z, z),
+    yxx => Vec3(y, x, x),
+    yxy => Vec3(y, x, y),
+    yxz => Vec3(y, x, z),
+    yyx => Vec3(y, y, x),
+    yyy => Vec3(y, y, y),
+    yyz => Vec3(y, y, z),
+    yzx => Vec3(y, z, x),
+    yzy => Vec3(y, z, y),
+    yzz => Vec3(y, z, z),
+    zxx => Vec3(z, x, x),
+    zxy => Vec3(z, x, y),
+    zxz => Vec3(z, x, z),
+    zyx => Vec3(z, y, x),
+    zyy => Vec3(z, y, y),
+    zyz => Vec3(z, y, z),
+    zzx => Vec3(z, z, x),
+    zzy => Vec3(z, z, y),
+    zzz => Vec3(z, z, z),
+});
+
+/// Generates constants for the floating point vector types.
+macro_rules! vec3_consts {
+    ($($f:ty),+) => {$(
+        impl Vec3<$f> {
+            /// Vector with all components set to `0`.
+            pub const ZERO: Self = Self::new(0., 0., 0.);
+            /// Vector with all components set to `1`.
+            pub const ONE: Self = Self::new(1., 1., 1.);
+            /// Unit vector along `+X`, i.e. forward.
+            pub const X: Self = Self::new(1., 0., 0.);
+            /// Unit vector along `+Y`, i.e. left.
+            pub const Y: Self = Self::new(0., 1., 0.);
+            /// Unit vector along `+Z`, i.e. top.
+            pub const Z: Self = Self::new(0., 0., 1.);
+        }
+    )+};
+}
+
+vec3_consts!(f32, f64);
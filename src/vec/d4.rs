@@ -1,6 +1,9 @@
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
-use super::{Vec2, Vec3};
-use num_traits::Float;
+use std::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::iter::{Product, Sum};
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+use super::{ParseVecError, Vec2, Vec3};
+use crate::{scalar::max, scalar::min, scalar::mul_add, scalar::relative_eq, Real, Scalar};
 
 pub type Vec4f = Vec4<f32>;
 pub type Vec4d = Vec4<f64>;
@@ -10,7 +13,7 @@ pub type Vec4d = Vec4<f64>;
 #[repr(C)]
 pub struct Vec4<F>
 where
-    F: Float,
+    F: Scalar,
 {
     pub x: F,
     pub y: F,
@@ -20,11 +23,11 @@ where
 
 impl<F> Vec4<F>
 where
-    F: Float,
+    F: Scalar,
 {
     /// Creates new vector.
     #[inline(always)]
-    pub fn new(x: F, y: F, z: F, w: F) -> Self {
+    pub const fn new(x: F, y: F, z: F, w: F) -> Self {
         Self { x, y, z, w }
     }
 
@@ -34,6 +37,24 @@ where
         Vec3::new(self.x, self.y, self.z)
     }
 
+    /// Drops the W component of the vector.
+    #[inline]
+    pub fn truncate(self) -> Vec3<F> {
+        Vec3::new(self.x, self.y, self.z)
+    }
+
+    /// Performs the perspective divide, dividing X, Y and Z components by W.
+    /// ```
+    /// # use ewq::vec::{Vec3f, Vec4f};
+    /// let v = Vec4f::new(2., 4., 6., 2.);
+    /// assert_eq!(v.truncate(), Vec3f::new(2., 4., 6.));
+    /// assert_eq!(v.project(), Vec3f::new(1., 2., 3.));
+    /// ```
+    #[inline]
+    pub fn project(self) -> Vec3<F> {
+        self.truncate() / self.w
+    }
+
     /// Creates new vector by expanding 3D vector.
     #[inline]
     pub fn from_vec3(v: Vec3<F>, w: F) -> Self {
@@ -104,16 +125,292 @@ where
         )
     }
 
-    /// Computes the distance between two vectors.
+    /// Computes dot product between two vectors.
     #[inline]
-    pub fn distance_to(&self, other: Self) -> F {
-        (other - *self).magnitude()
+    pub fn dot(&self, other: Self) -> F {
+        mul_add(
+            self.w,
+            other.w,
+            mul_add(self.z, other.z, mul_add(self.y, other.y, self.x * other.x)),
+        )
     }
 
-    /// Computes dot product between two vectors.
+    /// Linearly interpolates between two vectors.
+    /// `t = 0` yields `self` and `t = 1` yields `other`.
+    /// ```
+    /// # use ewq::vec::Vec4;
+    /// let a = Vec4::new(0., 1., 2., 3.);
+    /// let b = Vec4::new(4., 3., 2., 1.);
+    /// assert_eq!(a.lerp(b, 0.), a);
+    /// assert_eq!(a.lerp(b, 1.), b);
+    /// assert_eq!(a.lerp(b, 0.25), Vec4::new(1., 1.5, 2., 2.5));
+    /// ```
     #[inline]
-    pub fn dot(&self, other: Self) -> F {
-        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    pub fn lerp(&self, other: Self, t: F) -> Self {
+        *self + (other - *self) * t
+    }
+
+    /// Linearly interpolates between two vectors with `t` clamped to `[0, 1]`,
+    /// so the result never leaves the segment between `self` and `other`.
+    #[inline]
+    pub fn lerp_clamped(&self, other: Self, t: F) -> Self {
+        let t = if t < F::zero() {
+            F::zero()
+        } else if t > F::one() {
+            F::one()
+        } else {
+            t
+        };
+        self.lerp(other, t)
+    }
+
+    /// Computes the interpolation factor `t` for which `a.lerp(b, t)` is the closest to `value`,
+    /// i.e. `0` at `a` and `1` at `b`. The result is not clamped.
+    /// `a` and `b` must not be equal.
+    #[inline]
+    pub fn inverse_lerp(a: Self, b: Self, value: Self) -> F {
+        let ab = b - a;
+        (value - a).dot(ab) / ab.dot(ab)
+    }
+
+    /// Linearly maps every component from the range `from` to the range `to`,
+    /// where ranges are given as `(start, end)` pairs of vectors.
+    #[inline]
+    pub fn remap(&self, from: (Self, Self), to: (Self, Self)) -> Self {
+        let r = |v: F, a: F, b: F, c: F, d: F| c + (v - a) * (d - c) / (b - a);
+        Self::new(
+            r(self.x, from.0.x, from.1.x, to.0.x, to.1.x),
+            r(self.y, from.0.y, from.1.y, to.0.y, to.1.y),
+            r(self.z, from.0.z, from.1.z, to.0.z, to.1.z),
+            r(self.w, from.0.w, from.1.w, to.0.w, to.1.w),
+        )
+    }
+
+    /// Scales all of the components by `factor`.
+    #[inline]
+    pub fn scale(&mut self, factor: F) {
+        self.x = self.x * factor;
+        self.y = self.y * factor;
+        self.z = self.z * factor;
+        self.w = self.w * factor;
+    }
+
+    /// Computes the squared magnitude of the vector.
+    #[inline]
+    pub fn sqrt_magnitude(&self) -> F {
+        self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w
+    }
+
+    /// Projects the vector onto `other`.
+    /// ```
+    /// # use ewq::vec::Vec4;
+    /// let v = Vec4::new(3., 4., 5., 6.);
+    /// let onto = Vec4::new(0., 0., 0., 2.);
+    /// assert_eq!(v.project_onto(onto), Vec4::new(0., 0., 0., 6.));
+    /// assert_eq!(v.project_onto(onto) + v.reject_from(onto), v);
+    /// ```
+    #[inline]
+    pub fn project_onto(&self, other: Self) -> Self {
+        other * (self.dot(other) / other.sqrt_magnitude())
+    }
+
+    /// Projects the vector onto `other`, which must be normalized.
+    #[inline]
+    pub fn project_onto_normalized(&self, other: Self) -> Self {
+        other * self.dot(other)
+    }
+
+    /// Returns the component of the vector perpendicular to `other`.
+    #[inline]
+    pub fn reject_from(&self, other: Self) -> Self {
+        *self - self.project_onto(other)
+    }
+
+    /// Returns the component of the vector perpendicular to `other`, which must be normalized.
+    #[inline]
+    pub fn reject_from_normalized(&self, other: Self) -> Self {
+        *self - self.project_onto_normalized(other)
+    }
+
+    /// Performs cubic Hermite interpolation from `self` with tangent `tangent`
+    /// to `other` with tangent `other_tangent`.
+    /// ```
+    /// # use ewq::vec::Vec4f;
+    /// let a = Vec4f::new(0., 0., 0., 0.);
+    /// let b = Vec4f::new(2., 2., 2., 2.);
+    /// let m = Vec4f::new(0., 0., 0., 0.);
+    /// assert_eq!(a.hermite(m, b, m, 0.5), a.smoothstep(b, 0.5));
+    /// assert_eq!(a.hermite(m, b, m, 1.), b);
+    /// ```
+    #[inline]
+    pub fn hermite(&self, tangent: Self, other: Self, other_tangent: Self, t: F) -> Self {
+        let two = F::one() + F::one();
+        let three = two + F::one();
+        let (t2, t3) = (t * t, t * t * t);
+        *self * (two * t3 - three * t2 + F::one())
+            + tangent * (t3 - two * t2 + t)
+            + other * (three * t2 - two * t3)
+            + other_tangent * (t3 - t2)
+    }
+
+    /// Views the components of the vector as a slice.
+    /// ```
+    /// # use ewq::vec::Vec4f;
+    /// let mut v = Vec4f::new(1., 2., 3., 4.);
+    /// assert_eq!(v.as_slice(), &[1., 2., 3., 4.]);
+    /// v.as_mut_slice()[0] = 0.;
+    /// assert_eq!(v.x, 0.);
+    /// ```
+    #[inline]
+    pub fn as_slice(&self) -> &[F] {
+        // SAFETY: `#[repr(C)]` struct of 4 `F` fields has the same layout as `[F; 4]`.
+        unsafe { std::slice::from_raw_parts(self as *const Self as *const F, 4) }
+    }
+
+    /// Views the components of the vector as a mutable slice.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [F] {
+        // SAFETY: `#[repr(C)]` struct of 4 `F` fields has the same layout as `[F; 4]`.
+        unsafe { std::slice::from_raw_parts_mut(self as *mut Self as *mut F, 4) }
+    }
+
+    /// Returns an iterator over the components of the vector.
+    #[inline]
+    pub fn iter(&self) -> std::slice::Iter<'_, F> {
+        self.as_slice().iter()
+    }
+
+    /// Computes the sum of all components.
+    /// ```
+    /// # use ewq::vec::Vec4f;
+    /// let v = Vec4f::new(1., 2., 3., 4.);
+    /// assert_eq!(v.element_sum(), 10.);
+    /// assert_eq!(v.element_product(), 24.);
+    /// ```
+    #[inline]
+    pub fn element_sum(&self) -> F {
+        self.x + self.y + self.z + self.w
+    }
+
+    /// Computes the product of all components.
+    #[inline]
+    pub fn element_product(&self) -> F {
+        self.x * self.y * self.z * self.w
+    }
+
+    /// Returns the index of the smallest component of the vector.
+    #[inline]
+    pub fn min_axis(&self) -> usize {
+        (1..4).fold(0, |min, i| if self[i] < self[min] { i } else { min })
+    }
+
+    /// Returns the index of the largest component of the vector.
+    #[inline]
+    pub fn max_axis(&self) -> usize {
+        (1..4).fold(0, |max, i| if self[i] > self[max] { i } else { max })
+    }
+
+    /// Returns component-wise minimum of two vectors.
+    #[inline]
+    pub fn min(&self, other: Self) -> Self {
+        Self {
+            x: min(self.x, other.x),
+            y: min(self.y, other.y),
+            z: min(self.z, other.z),
+            w: min(self.w, other.w),
+        }
+    }
+
+    /// Returns component-wise maximum of two vectors.
+    #[inline]
+    pub fn max(&self, other: Self) -> Self {
+        Self {
+            x: max(self.x, other.x),
+            y: max(self.y, other.y),
+            z: max(self.z, other.z),
+            w: max(self.w, other.w),
+        }
+    }
+
+    /// Clamps each component of the vector between the corresponding components of `lo` and `hi`.
+    /// ```
+    /// # use ewq::vec::Vec4;
+    /// let v = Vec4::new(3., -1., 2., 0.);
+    /// let lo = Vec4::new(0., 0., 0., 0.);
+    /// let hi = Vec4::new(2., 2., 2., 2.);
+    /// assert_eq!(v.clamp(lo, hi), Vec4::new(2., 0., 2., 0.));
+    /// ```
+    #[inline]
+    pub fn clamp(&self, lo: Self, hi: Self) -> Self {
+        Self {
+            x: min(max(self.x, lo.x), hi.x),
+            y: min(max(self.y, lo.y), hi.y),
+            z: min(max(self.z, lo.z), hi.z),
+            w: min(max(self.w, lo.w), hi.w),
+        }
+    }
+
+    /// Returns the smallest component of the vector.
+    /// ```
+    /// # use ewq::vec::Vec4f;
+    /// let v = Vec4f::new(3., -1., 2., 0.);
+    /// assert_eq!(v.min_element(), -1.);
+    /// assert_eq!(v.max_element(), 3.);
+    /// assert_eq!(v.min_axis(), 1);
+    /// assert_eq!(v.max_axis(), 0);
+    /// ```
+    #[inline]
+    pub fn min_element(&self) -> F {
+        min(min(min(self.x, self.y), self.z), self.w)
+    }
+
+    /// Returns the largest component of the vector.
+    #[inline]
+    pub fn max_element(&self) -> F {
+        max(max(max(self.x, self.y), self.z), self.w)
+    }
+
+    /// Computes the least nonnegative remainder of every component
+    /// divided by the corresponding component of `rhs`.
+    #[inline]
+    pub fn rem_euclid(&self, rhs: Self) -> Self {
+        let r = |a: F, b: F| {
+            let r = a % b;
+            if r >= F::zero() {
+                return r;
+            }
+            // Tiny negative remainders can round up to `|b|` itself.
+            let r = if b < F::zero() { r - b } else { r + b };
+            if r == b || r == -b {
+                F::zero()
+            } else {
+                r
+            }
+        };
+        Self::new(
+            r(self.x, rhs.x),
+            r(self.y, rhs.y),
+            r(self.z, rhs.z),
+            r(self.w, rhs.w),
+        )
+    }
+
+    /// Wraps every component into the range `[min, max)`,
+    /// e.g. positions in a tiling world or texture coordinates.
+    #[inline]
+    pub fn wrap(&self, min: Self, max: Self) -> Self {
+        min + (*self - min).rem_euclid(max - min)
+    }
+}
+
+impl<F> Vec4<F>
+where
+    F: Real,
+{
+    /// Computes the distance between two vectors.
+    #[inline]
+    pub fn distance_to(&self, other: Self) -> F {
+        (other - *self).magnitude()
     }
 
     /// Computes dot product between two normalized copies of the vectors.
@@ -134,31 +431,31 @@ where
         F::acos(self.dot_normalized(other))
     }
 
-    /// Linearly interpolates between two vectors.
-    #[inline]
-    pub fn lerp(&self, other: Self, t: F) -> Self {
-        *self + (other - *self) * (F::one() - t)
-    }
-
-    /// Scales all of the components by `factor`.
+    /// Computes `self * a + b` component-wise,
+    /// rounding once for `f32` and `f64` if the `fma` feature is enabled.
+    /// ```
+    /// # use ewq::vec::Vec4f;
+    /// let v = Vec4f::new(1., 2., 3., 4.);
+    /// assert_eq!(v.mul_add(Vec4f::new(2., 2., 2., 2.), Vec4f::ONE), Vec4f::new(3., 5., 7., 9.));
+    /// ```
     #[inline]
-    pub fn scale(&mut self, factor: F) {
-        self.x = self.x * factor;
-        self.y = self.y * factor;
-        self.z = self.z * factor;
-        self.w = self.w * factor;
+    pub fn mul_add(&self, a: Self, b: Self) -> Self {
+        Self::new(
+            mul_add(self.x, a.x, b.x),
+            mul_add(self.y, a.y, b.y),
+            mul_add(self.z, a.z, b.z),
+            mul_add(self.w, a.w, b.w),
+        )
     }
 
     /// Computes the magnitude of the vector.
     #[inline]
     pub fn magnitude(&self) -> F {
-        F::sqrt(self.sqrt_magnitude())
-    }
-
-    /// Computes the squared magnitude of the vector.
-    #[inline]
-    pub fn sqrt_magnitude(&self) -> F {
-        self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w
+        F::sqrt(mul_add(
+            self.w,
+            self.w,
+            mul_add(self.z, self.z, mul_add(self.y, self.y, self.x * self.x)),
+        ))
     }
 
     /// Returns the normalized version of the vector.
@@ -173,6 +470,43 @@ where
         }
     }
 
+    /// Returns the normalized version of the vector
+    /// or `None` if its magnitude is too close to zero.
+    /// ```
+    /// # use ewq::vec::Vec4f;
+    /// assert_eq!(Vec4f::default().try_normalize(), None);
+    /// assert_eq!(Vec4f::new(0., 3., 0., 4.).try_normalize(), Some(Vec4f::new(0., 3., 0., 4.) / 5.));
+    /// ```
+    #[inline]
+    pub fn try_normalize(&self) -> Option<Self> {
+        let l = self.magnitude();
+        if l > F::epsilon() {
+            Some(*self / l)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the normalized version of the vector
+    /// or zero vector if its magnitude is too close to zero.
+    #[inline]
+    pub fn normalize_or_zero(&self) -> Self {
+        self.normalize_or(Self::new(F::zero(), F::zero(), F::zero(), F::zero()))
+    }
+
+    /// Returns the normalized version of the vector
+    /// or `fallback` if its magnitude is too close to zero.
+    /// ```
+    /// # use ewq::vec::Vec4f;
+    /// let fallback = Vec4f::new(1., 0., 0., 0.);
+    /// assert_eq!(Vec4f::default().normalize_or(fallback), fallback);
+    /// assert_eq!(Vec4f::default().normalize_or_zero(), Vec4f::default());
+    /// ```
+    #[inline]
+    pub fn normalize_or(&self, fallback: Self) -> Self {
+        self.try_normalize().unwrap_or(fallback)
+    }
+
     /// Normalizes the vector, preserving direction but reducing its magnitude to `1`.
     #[inline]
     pub fn normalize(&mut self) {
@@ -182,11 +516,170 @@ where
         self.z = self.z / l;
         self.w = self.w / l;
     }
+
+    /// Returns the vector with its magnitude limited to `max`.
+    /// ```
+    /// # use ewq::vec::Vec4f;
+    /// let v = Vec4f::new(0., 0., 3., 4.);
+    /// assert_eq!(v.clamp_magnitude(1.), Vec4f::new(0., 0., 0.6, 0.8));
+    /// assert_eq!(v.clamp_magnitude(10.), v);
+    /// ```
+    #[inline]
+    pub fn clamp_magnitude(&self, max: F) -> Self {
+        let l = self.magnitude();
+        if l > max {
+            *self * (max / l)
+        } else {
+            *self
+        }
+    }
+
+    /// Returns the vector with the same direction and magnitude of `magnitude`.
+    /// Zero vector is returned as is.
+    /// ```
+    /// # use ewq::vec::Vec4f;
+    /// assert_eq!(Vec4f::new(0., 0., 3., 4.).with_magnitude(10.), Vec4f::new(0., 0., 6., 8.));
+    /// ```
+    #[inline]
+    pub fn with_magnitude(&self, magnitude: F) -> Self {
+        self.normalize_or_zero() * magnitude
+    }
+
+    /// Moves the vector toward `target` by no more than `max_delta` distance.
+    /// ```
+    /// # use ewq::vec::Vec4f;
+    /// let v = Vec4f::new(0., 0., 0., 0.);
+    /// let target = Vec4f::new(0., 0., 0., 10.);
+    /// assert_eq!(v.move_toward(target, 3.), Vec4f::new(0., 0., 0., 3.));
+    /// assert_eq!(v.move_toward(target, 20.), target);
+    /// ```
+    #[inline]
+    pub fn move_toward(&self, target: Self, max_delta: F) -> Self {
+        let delta = target - *self;
+        let l = delta.magnitude();
+        if l <= max_delta || l <= F::epsilon() {
+            target
+        } else {
+            *self + delta * (max_delta / l)
+        }
+    }
+
+    /// Interpolates between two vectors with smoothstep easing,
+    /// `t` is clamped to `[0, 1]` range.
+    /// ```
+    /// # use ewq::vec::Vec4f;
+    /// let a = Vec4f::new(0., 0., 0., 0.);
+    /// let b = Vec4f::new(2., 2., 2., 2.);
+    /// assert_eq!(a.smoothstep(b, 0.), a);
+    /// assert_eq!(a.smoothstep(b, 0.25), Vec4f::new(0.3125, 0.3125, 0.3125, 0.3125));
+    /// assert_eq!(a.smoothstep(b, 2.), b);
+    /// ```
+    #[inline]
+    pub fn smoothstep(&self, other: Self, t: F) -> Self {
+        let t = t.max(F::zero()).min(F::one());
+        let three = F::one() + F::one() + F::one();
+        *self + (other - *self) * (t * t * (three - (t + t)))
+    }
+
+    /// Checks if all components of the vector are finite.
+    /// ```
+    /// # use ewq::vec::Vec4f;
+    /// let v = Vec4f::new(1., 1., 1., f32::INFINITY);
+    /// assert!(!v.is_finite());
+    /// assert!(!v.is_nan());
+    /// assert!((v * 0.).is_nan());
+    /// ```
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite() && self.w.is_finite()
+    }
+
+    /// Checks if any component of the vector is `NaN`.
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.x.is_nan() || self.y.is_nan() || self.z.is_nan() || self.w.is_nan()
+    }
+
+    /// Checks if all components of two vectors differ by no more than `epsilon`.
+    #[inline]
+    pub fn abs_diff_eq(&self, other: &Self, epsilon: F) -> bool {
+        self.as_slice()
+            .iter()
+            .zip(other.as_slice())
+            .all(|(a, b)| (*a - *b).abs() <= epsilon)
+    }
+
+    /// Checks if all components of two vectors are equal within `epsilon`
+    /// or within `max_relative` of the larger component magnitude.
+    #[inline]
+    pub fn relative_eq(&self, other: &Self, epsilon: F, max_relative: F) -> bool {
+        self.as_slice()
+            .iter()
+            .zip(other.as_slice())
+            .all(|(a, b)| relative_eq(*a, *b, epsilon, max_relative))
+    }
+
+    /// Computes the reciprocal `1 / c` of every component.
+    /// Zero components produce infinities.
+    #[inline]
+    pub fn recip(&self) -> Self {
+        Self::new(
+            self.x.recip(),
+            self.y.recip(),
+            self.z.recip(),
+            self.w.recip(),
+        )
+    }
+
+    /// Computes the centroid (arithmetic mean) of the points
+    /// or `None` if the slice is empty.
+    #[inline]
+    pub fn centroid(points: &[Self]) -> Option<Self> {
+        Self::mean(points.iter().copied())
+    }
+
+    /// Computes the arithmetic mean of the vectors
+    /// or `None` if the iterator is empty.
+    #[inline]
+    pub fn mean<I>(vectors: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        super::mean(vectors)
+    }
+
+    /// Computes the mean of the vectors weighted by the paired weights
+    /// or `None` if the weights sum up to zero.
+    #[inline]
+    pub fn weighted_mean<I>(vectors: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = (Self, F)>,
+    {
+        super::weighted_mean(vectors)
+    }
+
+    /// Converts the vector to the other floating point precision.
+    /// ```
+    /// # use ewq::vec::{Vec4d, Vec4f};
+    /// assert_eq!(Vec4d::new(0.1, 2., 3., 4.).cast::<f32>(), Vec4f::new(0.1, 2., 3., 4.));
+    /// ```
+    #[inline]
+    pub fn cast<T>(&self) -> Vec4<T>
+    where
+        T: Real,
+    {
+        Vec4::new(
+            T::from(self.x).unwrap(),
+            T::from(self.y).unwrap(),
+            T::from(self.z).unwrap(),
+            T::from(self.w).unwrap(),
+        )
+    }
 }
 
 impl<F> Add for Vec4<F>
 where
-    F: Float,
+    F: Scalar,
 {
     type Output = Self;
 
@@ -203,7 +696,7 @@ where
 
 impl<F> AddAssign for Vec4<F>
 where
-    F: Float,
+    F: Scalar,
 {
     #[inline]
     fn add_assign(&mut self, rhs: Self) {
@@ -216,7 +709,7 @@ where
 
 impl<F> Sub for Vec4<F>
 where
-    F: Float,
+    F: Scalar,
 {
     type Output = Self;
 
@@ -233,7 +726,7 @@ where
 
 impl<F> SubAssign for Vec4<F>
 where
-    F: Float,
+    F: Scalar,
 {
     #[inline]
     fn sub_assign(&mut self, rhs: Self) {
@@ -246,7 +739,7 @@ where
 
 impl<F> Mul<F> for Vec4<F>
 where
-    F: Float,
+    F: Scalar,
 {
     type Output = Self;
 
@@ -263,7 +756,7 @@ where
 
 impl<F> MulAssign<F> for Vec4<F>
 where
-    F: Float,
+    F: Scalar,
 {
     #[inline]
     fn mul_assign(&mut self, rhs: F) {
@@ -276,7 +769,7 @@ where
 
 impl<F> Div<F> for Vec4<F>
 where
-    F: Float,
+    F: Scalar,
 {
     type Output = Self;
 
@@ -293,7 +786,7 @@ where
 
 impl<F> DivAssign<F> for Vec4<F>
 where
-    F: Float,
+    F: Scalar,
 {
     #[inline]
     fn div_assign(&mut self, rhs: F) {
@@ -306,7 +799,7 @@ where
 
 impl<F> Neg for Vec4<F>
 where
-    F: Float,
+    F: Scalar,
 {
     type Output = Self;
 
@@ -320,3 +813,533 @@ where
         }
     }
 }
+
+impl<F> Index<usize> for Vec4<F>
+where
+    F: Scalar,
+{
+    type Output = F;
+
+    /// Gets the component by index.
+    /// # Panics
+    /// If index is `>3`.
+    /// ```
+    /// # use ewq::vec::Vec4f;
+    /// let mut v = Vec4f::new(1., 2., 3., 4.);
+    /// v[3] = 0.;
+    /// assert_eq!(v[0], 1.);
+    /// assert_eq!(v[3], 0.);
+    /// ```
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            3 => &self.w,
+            _ => panic!("Index out of range"),
+        }
+    }
+}
+
+impl<F> IndexMut<usize> for Vec4<F>
+where
+    F: Scalar,
+{
+    /// Gets the mutable component by index.
+    /// # Panics
+    /// If index is `>3`.
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            3 => &mut self.w,
+            _ => panic!("Index out of range"),
+        }
+    }
+}
+
+impl<F> AsRef<[F]> for Vec4<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn as_ref(&self) -> &[F] {
+        self.as_slice()
+    }
+}
+
+impl<F> AsMut<[F]> for Vec4<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn as_mut(&mut self) -> &mut [F] {
+        self.as_mut_slice()
+    }
+}
+
+impl<F> Sum for Vec4<F>
+where
+    F: Scalar,
+{
+    /// Sums the vectors.
+    /// ```
+    /// # use ewq::vec::Vec4f;
+    /// let points = [Vec4f::new(1., 2., 3., 4.), Vec4f::new(1., 2., 3., 4.)];
+    /// assert_eq!(points.iter().sum::<Vec4f>(), points[0] * 2.);
+    /// assert_eq!(points.into_iter().product::<Vec4f>(), Vec4f::new(1., 4., 9., 16.));
+    /// ```
+    #[inline]
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(
+            Self::new(F::zero(), F::zero(), F::zero(), F::zero()),
+            |acc, v| acc + v,
+        )
+    }
+}
+
+impl<'a, F> Sum<&'a Vec4<F>> for Vec4<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl<F> Product for Vec4<F>
+where
+    F: Scalar,
+{
+    /// Multiplies the vectors component-wise.
+    #[inline]
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(
+            Self::new(F::one(), F::one(), F::one(), F::one()),
+            |acc, v| Self {
+                x: acc.x * v.x,
+                y: acc.y * v.y,
+                z: acc.z * v.z,
+                w: acc.w * v.w,
+            },
+        )
+    }
+}
+
+impl<'a, F> Product<&'a Vec4<F>> for Vec4<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+impl<F> Display for Vec4<F>
+where
+    F: Scalar + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        super::fmt_tuple(f, &[self.x, self.y, self.z, self.w])
+    }
+}
+
+impl<F> FromStr for Vec4<F>
+where
+    F: Scalar + FromStr,
+{
+    type Err = ParseVecError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y, z, w] = super::parse_tuple(s)?;
+        Ok(Self::new(x, y, z, w))
+    }
+}
+
+macro_rules! vec4_scalar_mul {
+    ($($f:ty),+) => {$(
+        impl Mul<Vec4<$f>> for $f {
+            type Output = Vec4<$f>;
+
+            /// Multiplies the vector by the scalar.
+            /// ```
+            /// # use ewq::vec::Vec4;
+            #[doc = concat!("let v = Vec4::<", stringify!($f), ">::new(1., 2., 3., 4.);")]
+            /// assert_eq!(2. * v, v * 2.);
+            /// ```
+            #[inline]
+            fn mul(self, rhs: Vec4<$f>) -> Self::Output {
+                rhs * self
+            }
+        }
+    )+};
+}
+
+vec4_scalar_mul!(f32, f64);
+
+swizzle!(Vec4 {
+    xx => Vec2(x, x),
+    xy => Vec2(x, y),
+    xz => Vec2(x, z),
+    xw => Vec2(x, w),
+    yx => Vec2(y, x),
+    yy => Vec2(y, y),
+    yz => Vec2(y, z),
+    yw => Vec2(y, w),
+    zx => Vec2(z, x),
+    zy => Vec2(z, y),
+    zz => Vec2(z, z),
+    zw => Vec2(z, w),
+    wx => Vec2(w, x),
+    wy => Vec2(w, y),
+    wz => Vec2(w, z),
+    ww => Vec2(w, w),
+    xxx => Vec3(x, x, x),
+    xxy => Vec3(x, x, y),
+    xxz => Vec3(x, x, z),
+    xxw => Vec3(x, x, w),
+    xyx => Vec3(x, y, x),
+    xyy => Vec3(x, y, y),
+    xyz => Vec3(x, y, z),
+    xyw => Vec3(x, y, w),
+    xzx => Vec3(x, z, x),
+    xzy => Vec3(x, z, y),
+    xzz => Vec3(x, z, z),
+    xzw => Vec3(x, z, w),
+    xwx => Vec3(x, w, x),
+    xwy => Vec3(x, w, y),
+    xwz => Vec3(x, w, z),
+    xww => Vec3(x, w, w),
+    yxx => Vec3(y, x, x),
+    yxy => Vec3(y, x, y),
+    yxz => Vec3(y, x, z),
+    yxw => Vec3(y, x, w),
+    yyx => Vec3(y, y, x),
+    yyy => Vec3(y, y, y),
+    yyz => Vec3(y, y, z),
+    yyw => Vec3(y, y, w),
+    yzx => Vec3(y, z, x),
+    yzy => Vec3(y, z, y),
+    yzz => Vec3(y, z, z),
+    yzw => Vec3(y, z, w),
+    ywx => Vec3(y, w, x),
+    ywy => Vec3(y, w, y),
+    ywz => Vec3(y, w, z),
+    yww => Vec3(y, w, w),
+    zxx => Vec3(z, x, x),
+    zxy => Vec3(z, x, y),
+    zxz => Vec3(z, x, z),
+    zxw => Vec3(z, x, w),
+    zyx => Vec3(z, y, x),
+    zyy => Vec3(z, y, y),
+    zyz => Vec3(z, y, z),
+    zyw => Vec3(z, y, w),
+    zzx => Vec3(z, z, x),
+    zzy => Vec3(z, z, y),
+    zzz => Vec3(z, z, z),
+    zzw => Vec3(z, z, w),
+    zwx => Vec3(z, w, x),
+    zwy => Vec3(z, w, y),
+    zwz => Vec3(z, w, z),
+    zww => Vec3(z, w, w),
+    wxx => Vec3(w, x, x),
+    wxy => Vec3(w, x, y),
+    wxz => Vec3(w, x, z),
+    wxw => Vec3(w, x, w),
+    wyx => Vec3(w, y, x),
+    wyy => Vec3(w, y, y),
+    wyz => Vec3(w, y, z),
+    wyw => Vec3(w, y, w),
+    wzx => Vec3(w, z, x),
+    wzy => Vec3(w, z, y),
+    wzz => Vec3(w, z, z),
+    wzw => Vec3(w, z, w),
+    wwx => Vec3(w, w, x),
+    wwy => Vec3(w, w, y),
+    wwz => Vec3(w, w, z),
+    www => Vec3(w, w, w),
+    xxxx => Vec4(x, x, x, x),
+    xxxy => Vec4(x, x, x, y),
+    xxxz => Vec4(x, x, x, z),
+    xxxw => Vec4(x, x, x, w),
+    xxyx => Vec4(x, x, y, x),
+    xxyy => Vec4(x, x, y, y),
+    xxyz => Vec4(x, x, y, z),
+    xxyw => Vec4(x, x, y, w),
+    xxzx => Vec4(x, x, z, x),
+    xxzy => Vec4(x, x, z, y),
+    xxzz => Vec4(x, x, z, z),
+    xxzw => Vec4(x, x, z, w),
+    xxwx => Vec4(x, x, w, x),
+    xxwy => Vec4(x, x, w, y),
+    xxwz => Vec4(x, x, w, z),
+    xxww => Vec4(x, x, w, w),
+    xyxx => Vec4(x, y, x, x),
+    xyxy => Vec4(x, y, x, y),
+    xyxz => Vec4(x, y, x, z),
+    xyxw => Vec4(x, y, x, w),
+    xyyx => Vec4(x, y, y, x),
+    xyyy => Vec4(x, y, y, y),
+    xyyz => Vec4(x, y, y, z),
+    xyyw => Vec4(x, y, y, w),
+    xyzx => Vec4(x, y, z, x),
+    xyzy => Vec4(x, y, z, y),
+    xyzz => Vec4(x, y, z, z),
+    xyzw => Vec4(x, y, z, w),
+    xywx => Vec4(x, y, w, x),
+    xywy => Vec4(x, y, w, y),
+    xywz => Vec4(x, y, w, z),
+    xyww => Vec4(x, y, w, w),
+    xzxx => Vec4(x, z, x, x),
+    xzxy => Vec4(x, z, x, y),
+    xzxz => Vec4(x, z, x, z),
+    xzxw => Vec4(x, z, x, w),
+    xzyx => Vec4(x, z, y, x),
+    xzyy => Vec4(x, z, y, y),
+    xzyz => Vec4(x, z, y, z),
+    xzyw => Vec4(x, z, y, w),
+    xzzx => Vec4(x, z, z, x),
+    xzzy => Vec4(x, z, z, y),
+    xzzz => Vec4(x, z, z, z),
+    xzzw => Vec4(x, z, z, w),
+    xzwx => Vec4(x, z, w, x),
+    xzwy => Vec4(x, z, w, y),
+    xzwz => Vec4(x, z, w, z),
+    xzww => Vec4(x, z, w, w),
+    xwxx => Vec4(x, w, x, x),
+    xwxy => Vec4(x, w, x, y),
+    xwxz => Vec4(x, w, x, z),
+    xwxw => Vec4(x, w, x, w),
+    xwyx => Vec4(x, w, y, x),
+    xwyy => Vec4(x, w, y, y),
+    xwyz => Vec4(x, w, y, z),
+    xwyw => Vec4(x, w, y, w),
+    xwzx => Vec4(x, w, z, x),
+    xwzy => Vec4(x, w, z, y),
+    xwzz => Vec4(x, w, z, z),
+    xwzw => Vec4(x, w, z, w),
+    xwwx => Vec4(x, w, w, x),
+    xwwy => Vec4(x, w, w, y),
+    xwwz => Vec4(x, w, w, z),
+    xwww => Vec4(x, w, w, w),
+    yxxx => Vec4(y, x, x, x),
+    yxxy => Vec4(y, x, x, y),
+    yxxz => Vec4(y, x, x, z),
+    yxxw => Vec4(y, x, x, w),
+    yxyx => Vec4(y, x, y, x),
+    yxyy => Vec4(y, x, y, y),
+    yxyz => Vec4(y, x, y, z),
+    yxyw => Vec4(y, x, y, w),
+    yxzx => Vec4(y, x, z, x),
+    yxzy => Vec4(y, x, z, y),
+    yxzz => Vec4(y, x, z, z),
+    yxzw => Vec4(y, x, z, w),
+    yxwx => Vec4(y, x, w, x),
+    yxwy => Vec4(y, x, w, y),
+    yxwz => Vec4(y, x, w, z),
+    yxww => Vec4(y, x, w, w),
+    yyxx => Vec4(y, y, x, x),
+    yyxy => Vec4(y, y, x, y),
+    yyxz => Vec4(y, y, x, z),
+    yyxw => Vec4(y, y, x, w),
+    yyyx => Vec4(y, y, y, x),
+    yyyy => Vec4(y, y, y, y),
+    yyyz => Vec4(y, y, y, z),
+    yyyw => Vec4(y, y, y, w),
+    yyzx => Vec4(y, y, z, x),
+    yyzy => Vec4(y, y, z, y),
+    yyzz => Vec4(y, y, z, z),
+    yyzw => Vec4(y, y, z, w),
+    yywx => Vec4(y, y, w, x),
+    yywy => Vec4(y, y, w, y),
+    yywz => Vec4(y, y, w, z),
+    yyww => Vec4(y, y, w, w),
+    yzxx => Vec4(y, z, x, x),
+    yzxy => Vec4(y, z, x, y),
+    yzxz => Vec4(y, z, x, z),
+    yzxw => Vec4(y, z, x, w),
+    yzyx => Vec4(y, z, y, x),
+    yzyy => Vec4(y, z, y, y),
+    yzyz => Vec4(y, z, y, z),
+    yzyw => Vec4(y, z, y, w),
+    yzzx => Vec4(y, z, z, x),
+    yzzy => Vec4(y, z, z, y),
+    yzzz => Vec4(y, z, z, z),
+    yzzw => Vec4(y, z, z, w),
+    yzwx => Vec4(y, z, w, x),
+    yzwy => Vec4(y, z, w, y),
+    yzwz => Vec4(y, z, w, z),
+    yzww => Vec4(y, z, w, w),
+    ywxx => Vec4(y, w, x, x),
+    ywxy => Vec4(y, w, x, y),
+    ywxz => Vec4(y, w, x, z),
+    ywxw => Vec4(y, w, x, w),
+    ywyx => Vec4(y, w, y, x),
+    ywyy => Vec4(y, w, y, y),
+    ywyz => Vec4(y, w, y, z),
+    ywyw => Vec4(y, w, y, w),
+    ywzx => Vec4(y, w, z, x),
+    ywzy => Vec4(y, w, z, y),
+    ywzz => Vec4(y, w, z, z),
+    ywzw => Vec4(y, w, z, w),
+    ywwx => Vec4(y, w, w, x),
+    ywwy => Vec4(y, w, w, y),
+    ywwz => Vec4(y, w, w, z),
+    ywww => Vec4(y, w, w, w),
+    zxxx => Vec4(z, x, x, x),
+    zxxy => Vec4(z, x, x, y),
+    zxxz => Vec4(z, x, x, z),
+    zxxw => Vec4(z, x, x, w),
+    zxyx => Vec4(z, x, y, x),
+    zxyy => Vec4(z, x, y, y),
+    zxyz => Vec4(z, x, y, z),
+    zxyw => Vec4(z, x, y, w),
+    zxzx => Vec4(z, x, z, x),
+    zxzy => Vec4(z, x, z, y),
+    zxzz => Vec4(z, x, z, z),
+    zxzw => Vec4(z, x, z, w),
+    zxwx => Vec4(z, x, w, x),
+    zxwy => Vec4(z, x, w, y),
+    zxwz => Vec4(z, x, w, z),
+    zxww => Vec4(z, x, w, w),
+    zyxx => Vec4(z, y, x, x),
+    zyxy => Vec4(z, y, x, y),
+    zyxz => Vec4(z, y, x, z),
+    zyxw => Vec4(z, y, x, w),
+    zyyx => Vec4(z, y, y, x),
+    zyyy => Vec4(z, y, y, y),
+    zyyz => Vec4(z, y, y, z),
+    zyyw => Vec4(z, y, y, w),
+    zyzx => Vec4(z, y, z, x),
+    zyzy => Vec4(z, y, z, y),
+    zyzz => Vec4(z, y, z, z),
+    zyzw => Vec4(z, y, z, w),
+    zywx => Vec4(z, y, w, x),
+    zywy => Vec4(z, y, w, y),
+    zywz => Vec4(z, y, w, z),
+    zyww => Vec4(z, y, w, w),
+    zzxx => Vec4(z, z, x, x),
+    zzxy => Vec4(z, z, x, y),
+    zzxz => Vec4(z, z, x, z),
+    zzxw => Vec4(z, z, x, w),
+    zzyx => Vec4(z, z, y, x),
+    zzyy => Vec4(z, z, y, y),
+    zzyz => Vec4(z, z, y, z),
+    zzyw => Vec4(z, z, y, w),
+    zzzx => Vec4(z, z, z, x),
+    zzzy => Vec4(z, z, z, y),
+    zzzz => Vec4(z, z, z, z),
+    zzzw => Vec4(z, z, z, w),
+    zzwx => Vec4(z, z, w, x),
+    zzwy => Vec4(z, z, w, y),
+    zzwz => Vec4(z, z, w, z),
+    zzww => Vec4(z, z, w, w),
+    zwxx => Vec4(z, w, x, x),
+    zwxy => Vec4(z, w, x, y),
+    zwxz => Vec4(z, w, x, z),
+    zwxw => Vec4(z, w, x, w),
+    zwyx => Vec4(z, w, y, x),
+    zwyy => Vec4(z, w, y, y),
+    zwyz => Vec4(z, w, y, z),
+    zwyw => Vec4(z, w, y, w),
+    zwzx => Vec4(z, w, z, x),
+    zwzy => Vec4(z, w, z, y),
+    zwzz => Vec4(z, w, z, z),
+    zwzw => Vec4(z, w, z, w),
+    zwwx => Vec4(z, w, w, x),
+    zwwy => Vec4(z, w, w, y),
+    zwwz => Vec4(z, w, w, z),
+    zwww => Vec4(z, w, w, w),
+    wxxx => Vec4(w, x, x, x),
+    wxxy => Vec4(w, x, x, y),
+    wxxz => Vec4(w, x, x, z),
+    wxxw => Vec4(w, x, x, w),
+    wxyx => Vec4(w, x, y, x),
+    wxyy => Vec4(w, x, y, y),
+    wxyz => Vec4(w, x, y, z),
+    wxyw => Vec4(w, x, y, w),
+    wxzx => Vec4(w, x, z, x),
+    wxzy => Vec4(w, x, z, y),
+    wxzz => Vec4(w, x, z, z),
+    wxzw => Vec4(w, x, z, w),
+    wxwx => Vec4(w, x, w, x),
+    wxwy => Vec4(w, x, w, y),
+    wxwz => Vec4(w, x, w, z),
+    wxww => Vec4(w, x, w, w),
+    wyxx => Vec4(w, y, x, x),
+    wyxy => Vec4(w, y, x, y),
+    wyxz => Vec4(w, y, x, z),
+    wyxw => Vec4(w, y, x, w),
+    wyyx => Vec4(w, y, y, x),
+    wyyy => Vec4(w, y, y, y),
+    wyyz => Vec4(w, y, y, z),
+    wyyw => Vec4(w, y, y, w),
+    wyzx => Vec4(w, y, z, x),
+    wyzy => Vec4(w, y, z, y),
+    wyzz => Vec4(w, y, z, z),
+    wyzw => Vec4(w, y, z, w),
+    wywx => Vec4(w, y, w, x),
+    wywy => Vec4(w, y, w, y),
+    wywz => Vec4(w, y, w, z),
+    wyww => Vec4(w, y, w, w),
+    wzxx => Vec4(w, z, x, x),
+    wzxy => Vec4(w, z, x, y),
+    wzxz => Vec4(w, z, x, z),
+    wzxw => Vec4(w, z, x, w),
+    wzyx => Vec4(w, z, y, x),
+    wzyy => Vec4(w, z, y, y),
+    wzyz => Vec4(w, z, y, z),
+    wzyw => Vec4(w, z, y, w),
+    wzzx => Vec4(w, z, z, x),
+    wzzy => Vec4(w, z, z, y),
+    wzzz => Vec4(w, z, z, z),
+    wzzw => Vec4(w, z, z, w),
+    wzwx => Vec4(w, z, w, x),
+    wzwy => Vec4(w, z, w, y),
+    wzwz => Vec4(w, z, w, z),
+    wzww => Vec4(w, z, w, w),
+    wwxx => Vec4(w, w, x, x),
+    wwxy => Vec4(w, w, x, y),
+    wwxz => Vec4(w, w, x, z),
+    wwxw => Vec4(w, w, x, w),
+    wwyx => Vec4(w, w, y, x),
+    wwyy => Vec4(w, w, y, y),
+    wwyz => Vec4(w, w, y, z),
+    wwyw => Vec4(w, w, y, w),
+    wwzx => Vec4(w, w, z, x),
+    wwzy => Vec4(w, w, z, y),
+    wwzz => Vec4(w, w, z, z),
+    wwzw => Vec4(w, w, z, w),
+    wwwx => Vec4(w, w, w, x),
+    wwwy => Vec4(w, w, w, y),
+    wwwz => Vec4(w, w, w, z),
+    wwww => Vec4(w, w, w, w),
+});
+
+/// Generates constants for the floating point vector types.
+macro_rules! vec4_consts {
+    ($($f:ty),+) => {$(
+        impl Vec4<$f> {
+            /// Vector with all components set to `0`.
+            pub const ZERO: Self = Self::new(0., 0., 0., 0.);
+            /// Vector with all components set to `1`.
+            pub const ONE: Self = Self::new(1., 1., 1., 1.);
+            /// Unit vector along `+X`.
+            pub const X: Self = Self::new(1., 0., 0., 0.);
+            /// Unit vector along `+Y`.
+            pub const Y: Self = Self::new(0., 1., 0., 0.);
+            /// Unit vector along `+Z`.
+            pub const Z: Self = Self::new(0., 0., 1., 0.);
+            /// Unit vector along `+W`.
+            pub const W: Self = Self::new(0., 0., 0., 1.);
+        }
+    )+};
+}
+
+vec4_consts!(f32, f64);
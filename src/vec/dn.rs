@@ -0,0 +1,397 @@
+use std::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+use crate::{scalar::mul_add, scalar::relative_eq, Real, Scalar};
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+use super::{ParseVecError, Vec2, Vec3, Vec4};
+use crate::mat::Matrix;
+
+/// Generic Euclidian vector with `N` components.
+/// Fixed size vector types can be converted to and from it.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[repr(C)]
+pub struct VecN<F, const N: usize>
+where
+    F: Scalar,
+{
+    v: [F; N],
+}
+
+impl<F, const N: usize> VecN<F, N>
+where
+    F: Scalar,
+{
+    /// Creates new vector from components.
+    #[inline]
+    pub const fn new(components: [F; N]) -> Self {
+        Self { v: components }
+    }
+
+    /// Creates new vector filled with zeros.
+    #[inline]
+    pub fn zero() -> Self {
+        Self { v: [F::zero(); N] }
+    }
+
+    /// Returns the components of the vector.
+    #[inline]
+    pub fn components(&self) -> [F; N] {
+        self.v
+    }
+
+    /// Views the components of the vector as a slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[F] {
+        &self.v
+    }
+
+    /// Views the components of the vector as a mutable slice.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [F] {
+        &mut self.v
+    }
+
+    /// Returns an iterator over the components of the vector.
+    #[inline]
+    pub fn iter(&self) -> std::slice::Iter<'_, F> {
+        self.v.iter()
+    }
+
+    /// Computes dot product between two vectors.
+    /// ```
+    /// # use ewq::vec::VecN;
+    /// let a = VecN::new([1., 2., 3., 4., 5., 6.]);
+    /// let b = VecN::new([1., 0., 1., 0., 1., 0.]);
+    /// assert_eq!(a.dot(b), 9.);
+    /// ```
+    #[inline]
+    pub fn dot(&self, other: Self) -> F {
+        self.v
+            .iter()
+            .zip(other.v.iter())
+            .fold(F::zero(), |acc, (a, b)| mul_add(*a, *b, acc))
+    }
+
+    /// Computes the squared magnitude of the vector.
+    #[inline]
+    pub fn sqrt_magnitude(&self) -> F {
+        self.dot(*self)
+    }
+}
+
+impl<F, const N: usize> VecN<F, N>
+where
+    F: Real,
+{
+    /// Computes the magnitude (length) of the vector.
+    #[inline]
+    pub fn magnitude(&self) -> F {
+        self.sqrt_magnitude().sqrt()
+    }
+
+    /// Computes the distance between two points.
+    #[inline]
+    pub fn distance_to(&self, other: Self) -> F {
+        (other - *self).magnitude()
+    }
+
+    /// Returns the normalized version of the vector.
+    #[inline]
+    pub fn normalized(&self) -> Self {
+        *self / self.magnitude()
+    }
+
+    /// Returns the normalized version of the vector
+    /// or `None` if its magnitude is too close to zero.
+    #[inline]
+    pub fn try_normalize(&self) -> Option<Self> {
+        let l = self.magnitude();
+        if l > F::epsilon() {
+            Some(*self / l)
+        } else {
+            None
+        }
+    }
+
+    /// Checks if all components of two vectors differ by no more than `epsilon`.
+    #[inline]
+    pub fn abs_diff_eq(&self, other: &Self, epsilon: F) -> bool {
+        self.v
+            .iter()
+            .zip(other.v.iter())
+            .all(|(a, b)| (*a - *b).abs() <= epsilon)
+    }
+
+    /// Checks if all components of two vectors are equal within `epsilon`
+    /// or within `max_relative` of the larger component magnitude.
+    #[inline]
+    pub fn relative_eq(&self, other: &Self, epsilon: F, max_relative: F) -> bool {
+        self.v
+            .iter()
+            .zip(other.v.iter())
+            .all(|(a, b)| relative_eq(*a, *b, epsilon, max_relative))
+    }
+}
+
+impl<F, const N: usize> Default for VecN<F, N>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<F, const N: usize> Index<usize> for VecN<F, N>
+where
+    F: Scalar,
+{
+    type Output = F;
+
+    /// Gets the component by index.
+    /// # Panics
+    /// If `index >= N`.
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.v[index]
+    }
+}
+
+impl<F, const N: usize> IndexMut<usize> for VecN<F, N>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.v[index]
+    }
+}
+
+impl<F, const N: usize> Add for VecN<F, N>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self.add_assign(rhs);
+        self
+    }
+}
+
+impl<F, const N: usize> AddAssign for VecN<F, N>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        for (a, b) in self.v.iter_mut().zip(rhs.v.iter()) {
+            *a = *a + *b;
+        }
+    }
+}
+
+impl<F, const N: usize> Sub for VecN<F, N>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self.sub_assign(rhs);
+        self
+    }
+}
+
+impl<F, const N: usize> SubAssign for VecN<F, N>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        for (a, b) in self.v.iter_mut().zip(rhs.v.iter()) {
+            *a = *a - *b;
+        }
+    }
+}
+
+impl<F, const N: usize> Mul<F> for VecN<F, N>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    #[inline]
+    fn mul(mut self, rhs: F) -> Self::Output {
+        self.mul_assign(rhs);
+        self
+    }
+}
+
+impl<F, const N: usize> MulAssign<F> for VecN<F, N>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn mul_assign(&mut self, rhs: F) {
+        for a in self.v.iter_mut() {
+            *a = *a * rhs;
+        }
+    }
+}
+
+impl<F, const N: usize> Div<F> for VecN<F, N>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    #[inline]
+    fn div(mut self, rhs: F) -> Self::Output {
+        self.div_assign(rhs);
+        self
+    }
+}
+
+impl<F, const N: usize> DivAssign<F> for VecN<F, N>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn div_assign(&mut self, rhs: F) {
+        for a in self.v.iter_mut() {
+            *a = *a / rhs;
+        }
+    }
+}
+
+impl<F, const N: usize> Neg for VecN<F, N>
+where
+    F: Scalar,
+{
+    type Output = Self;
+
+    #[inline]
+    fn neg(mut self) -> Self::Output {
+        for a in self.v.iter_mut() {
+            *a = -*a;
+        }
+        self
+    }
+}
+
+impl<F, const N: usize> Display for VecN<F, N>
+where
+    F: Scalar + Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        super::fmt_tuple(f, &self.v)
+    }
+}
+
+impl<F, const N: usize> FromStr for VecN<F, N>
+where
+    F: Scalar + FromStr,
+{
+    type Err = ParseVecError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        super::parse_tuple(s).map(Self::new)
+    }
+}
+
+impl<F, const R: usize, const C: usize> Mul<VecN<F, C>> for Matrix<F, R, C>
+where
+    F: Scalar,
+{
+    type Output = VecN<F, R>;
+
+    /// Multiplies the matrix by the column vector.
+    /// ```
+    /// # use ewq::{mat::Matrix, vec::VecN};
+    /// let m = Matrix::new([[1., 2., 3.], [4., 5., 6.]]);
+    /// assert_eq!(m * VecN::new([1., 0., 1.]), VecN::new([4., 10.]));
+    /// ```
+    #[inline]
+    fn mul(self, rhs: VecN<F, C>) -> Self::Output {
+        let rows = self.rows();
+        let mut out = VecN::zero();
+        for (o, row) in out.v.iter_mut().zip(rows.iter()) {
+            *o = VecN::new(*row).dot(rhs);
+        }
+        out
+    }
+}
+
+impl<F> From<Vec2<F>> for VecN<F, 2>
+where
+    F: Scalar,
+{
+    /// Converts the vector.
+    /// ```
+    /// # use ewq::vec::{Vec2f, VecN};
+    /// let v = Vec2f::new(1., 2.);
+    /// assert_eq!(VecN::from(v), VecN::new([1., 2.]));
+    /// assert_eq!(Vec2f::from(VecN::from(v)), v);
+    /// ```
+    #[inline]
+    fn from(v: Vec2<F>) -> Self {
+        Self::new([v.x, v.y])
+    }
+}
+
+impl<F> From<VecN<F, 2>> for Vec2<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn from(v: VecN<F, 2>) -> Self {
+        let [x, y] = v.v;
+        Self::new(x, y)
+    }
+}
+
+impl<F> From<Vec3<F>> for VecN<F, 3>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn from(v: Vec3<F>) -> Self {
+        Self::new([v.x, v.y, v.z])
+    }
+}
+
+impl<F> From<VecN<F, 3>> for Vec3<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn from(v: VecN<F, 3>) -> Self {
+        let [x, y, z] = v.v;
+        Self::new(x, y, z)
+    }
+}
+
+impl<F> From<Vec4<F>> for VecN<F, 4>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn from(v: Vec4<F>) -> Self {
+        Self::new([v.x, v.y, v.z, v.w])
+    }
+}
+
+impl<F> From<VecN<F, 4>> for Vec4<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn from(v: VecN<F, 4>) -> Self {
+        let [x, y, z, w] = v.v;
+        Self::new(x, y, z, w)
+    }
+}
@@ -0,0 +1,52 @@
+use half::f16;
+use super::{Vec2, Vec3, Vec4};
+
+/// Generates packed half precision vector type along with its conversions.
+macro_rules! half_vec {
+    ($(#[$meta:meta])* $name:ident, $float:ident, $dim:literal, $($c:ident),+) => {
+        #[doc = concat!($dim, "D vector with half precision components, intended for compact storage.")]
+        $(#[$meta])*
+        #[derive(Debug, PartialEq, Default, Clone, Copy)]
+        #[repr(C)]
+        pub struct $name {
+            $(pub $c: f16,)+
+        }
+
+        impl $name {
+            /// Creates new vector.
+            #[inline(always)]
+            pub const fn new($($c: f16),+) -> Self {
+                Self { $($c),+ }
+            }
+        }
+
+        impl From<$float<f32>> for $name {
+            /// Converts the vector, rounding components to the nearest representable value.
+            #[inline]
+            fn from(v: $float<f32>) -> Self {
+                Self { $($c: f16::from_f32(v.$c)),+ }
+            }
+        }
+
+        impl From<$name> for $float<f32> {
+            /// Converts the vector losslessly.
+            #[inline]
+            fn from(v: $name) -> Self {
+                $float::new($(v.$c.to_f32()),+)
+            }
+        }
+    };
+}
+
+half_vec!(Vec2h, Vec2, 2, x, y);
+half_vec!(
+    /// ```
+    /// # use ewq::vec::{Vec3f, Vec3h};
+    /// let h = Vec3h::from(Vec3f::new(0.1, 1., 2049.));
+    /// let v = Vec3f::from(h);
+    /// assert_eq!(v, Vec3f::new(0.099975586, 1., 2048.));
+    /// assert_eq!(Vec3h::from(v), h);
+    /// ```
+    Vec3h, Vec3, 3, x, y, z
+);
+half_vec!(Vec4h, Vec4, 4, x, y, z, w);
@@ -0,0 +1,96 @@
+use std::hash::{Hash, Hasher};
+use crate::Real;
+use super::{Vec2, Vec3, Vec4};
+
+/// Returns the bit pattern of `x` that is used for hashing and comparison.
+/// Positive and negative zeros share the same pattern.
+#[inline]
+fn key<F>(x: F) -> (u64, i16, i8)
+where
+    F: Real,
+{
+    if x == F::zero() {
+        F::zero().integer_decode()
+    } else {
+        x.integer_decode()
+    }
+}
+
+/// Generates vector wrapper with bitwise `Eq` and `Hash`.
+macro_rules! hashable_vec {
+    ($(#[$meta:meta])* $name:ident, $vec:ident, $($c:ident),+) => {
+        #[doc = concat!("[`", stringify!($vec), "`] wrapper implementing `Eq` and `Hash` by comparing bit patterns of the components,")]
+        /// so it can be used as a key of hash maps and sets.
+        /// Positive and negative zeros are considered equal, as are NaNs with the same bit pattern.
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy)]
+        #[repr(transparent)]
+        pub struct $name<F>(pub $vec<F>)
+        where
+            F: Real;
+
+        impl<F> PartialEq for $name<F>
+        where
+            F: Real,
+        {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                $(key(self.0.$c) == key(other.0.$c))&&+
+            }
+        }
+
+        impl<F> Eq for $name<F> where F: Real {}
+
+        impl<F> Hash for $name<F>
+        where
+            F: Real,
+        {
+            #[inline]
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                $(key(self.0.$c).hash(state);)+
+            }
+        }
+
+        impl<F> From<$vec<F>> for $name<F>
+        where
+            F: Real,
+        {
+            #[inline]
+            fn from(v: $vec<F>) -> Self {
+                Self(v)
+            }
+        }
+
+        impl<F> From<$name<F>> for $vec<F>
+        where
+            F: Real,
+        {
+            #[inline]
+            fn from(v: $name<F>) -> Self {
+                v.0
+            }
+        }
+    };
+}
+
+hashable_vec!(HashableVec2, Vec2, x, y);
+hashable_vec!(
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use ewq::vec::{HashableVec3, Vec3f};
+    /// let vertices = [Vec3f::new(0., 1., 0.), Vec3f::new(1., 0., 0.), Vec3f::new(-0., 1., 0.)];
+    /// let mut indices = HashMap::new();
+    /// for v in vertices {
+    ///     let next = indices.len();
+    ///     indices.entry(HashableVec3(v)).or_insert(next);
+    /// }
+    /// assert_eq!(indices.len(), 2);
+    /// assert_eq!(indices[&HashableVec3::from(Vec3f::new(0., 1., 0.))], 0);
+    /// ```
+    HashableVec3,
+    Vec3,
+    x,
+    y,
+    z
+);
+hashable_vec!(HashableVec4, Vec4, x, y, z, w);
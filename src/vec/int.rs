@@ -0,0 +1,345 @@
+use std::ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+use num_traits::{PrimInt, Signed, WrappingAdd, WrappingSub};
+use crate::Real;
+use super::{ParseVecError, Vec2, Vec3, Vec4};
+
+pub type Vec2i = IVec2<i32>;
+pub type Vec2l = IVec2<i64>;
+pub type Vec3i = IVec3<i32>;
+pub type Vec3l = IVec3<i64>;
+pub type Vec4i = IVec4<i32>;
+pub type Vec4l = IVec4<i64>;
+pub type Vec2u = IVec2<u32>;
+pub type Vec3u = IVec3<u32>;
+pub type Vec4u = IVec4<u32>;
+
+/// Generates integer vector type along with its operators.
+macro_rules! int_vec {
+    ($(#[$meta:meta])* $name:ident, $float:ident, $dim:literal, $($c:ident: $i:literal),+) => {
+        #[doc = concat!($dim, "D vector with integer components.")]
+        $(#[$meta])*
+        #[derive(Debug, PartialEq, Eq, Hash, Default, Clone, Copy)]
+        #[repr(C)]
+        pub struct $name<I>
+        where
+            I: PrimInt,
+        {
+            $(pub $c: I,)+
+        }
+
+        impl<I> $name<I>
+        where
+            I: PrimInt,
+        {
+            /// Creates new vector.
+            #[inline(always)]
+            pub const fn new($($c: I),+) -> Self {
+                Self { $($c),+ }
+            }
+
+            /// Computes dot product between two vectors.
+            #[inline]
+            pub fn dot(&self, other: Self) -> I {
+                I::zero() $(+ self.$c * other.$c)+
+            }
+
+            /// Returns component-wise minimum of two vectors.
+            #[inline]
+            pub fn min(&self, other: Self) -> Self {
+                Self { $($c: self.$c.min(other.$c)),+ }
+            }
+
+            /// Returns component-wise maximum of two vectors.
+            #[inline]
+            pub fn max(&self, other: Self) -> Self {
+                Self { $($c: self.$c.max(other.$c)),+ }
+            }
+
+            /// Clamps each component of the vector between the corresponding components of `lo` and `hi`.
+            #[inline]
+            pub fn clamp(&self, lo: Self, hi: Self) -> Self {
+                self.max(lo).min(hi)
+            }
+
+            /// Converts the vector to the floating point one, rounding components
+            /// that do not fit into the mantissa of `F`, e.g. `i32` above `2^24` for `f32`.
+            /// For a lossless conversion of `i32` vectors use `From` into `f64` vectors.
+            #[inline]
+            pub fn to_float_lossy<F>(&self) -> $float<F>
+            where
+                F: Real,
+            {
+                $float::new($(F::from(self.$c).unwrap()),+)
+            }
+
+            /// Converts floating point vector to the integer one, truncating toward zero.
+            /// Returns `None` if any component is `NaN` or does not fit into `I`.
+            #[inline]
+            pub fn from_float<F>(v: $float<F>) -> Option<Self>
+            where
+                F: Real,
+            {
+                Some(Self { $($c: I::from(v.$c)?),+ })
+            }
+
+            /// Adds two vectors component-wise, saturating at the numeric bounds.
+            #[inline]
+            pub fn saturating_add(&self, other: Self) -> Self {
+                Self { $($c: self.$c.saturating_add(other.$c)),+ }
+            }
+
+            /// Subtracts two vectors component-wise, saturating at the numeric bounds.
+            #[inline]
+            pub fn saturating_sub(&self, other: Self) -> Self {
+                Self { $($c: self.$c.saturating_sub(other.$c)),+ }
+            }
+
+            /// Adds two vectors component-wise, wrapping around at the numeric bounds.
+            #[inline]
+            pub fn wrapping_add(&self, other: Self) -> Self
+            where
+                I: WrappingAdd,
+            {
+                Self { $($c: self.$c.wrapping_add(&other.$c)),+ }
+            }
+
+            /// Subtracts two vectors component-wise, wrapping around at the numeric bounds.
+            #[inline]
+            pub fn wrapping_sub(&self, other: Self) -> Self
+            where
+                I: WrappingSub,
+            {
+                Self { $($c: self.$c.wrapping_sub(&other.$c)),+ }
+            }
+
+            /// Converts coordinates into the linear index of the row-major grid of `size`,
+            /// with X being the fastest changing axis.
+            /// Returns `None` if coordinates are outside of the grid.
+            #[inline]
+            pub fn to_linear_index(&self, size: Self) -> Option<usize> {
+                [$((self.$c, size.$c)),+]
+                    .iter()
+                    .try_fold((0usize, 1usize), |(index, stride), &(c, extent)| {
+                        if c < I::zero() || c >= extent {
+                            return None;
+                        }
+                        let index = index.checked_add(c.to_usize()?.checked_mul(stride)?)?;
+                        Some((index, stride.checked_mul(extent.to_usize()?)?))
+                    })
+                    .map(|(index, _)| index)
+            }
+
+            /// Converts the linear index of the row-major grid of `size` into coordinates.
+            /// Returns `None` if index is outside of the grid.
+            #[inline]
+            pub fn from_linear_index(index: usize, size: Self) -> Option<Self> {
+                let mut rest = index;
+                $(
+                    let extent = size.$c.to_usize().filter(|&e| e > 0)?;
+                    let $c = I::from(rest % extent)?;
+                    rest /= extent;
+                )+
+                if rest == 0 {
+                    Some(Self { $($c),+ })
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl<I> Add for $name<I>
+        where
+            I: PrimInt,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self::Output {
+                Self { $($c: self.$c + rhs.$c),+ }
+            }
+        }
+
+        impl<I> AddAssign for $name<I>
+        where
+            I: PrimInt,
+        {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl<I> Sub for $name<I>
+        where
+            I: PrimInt,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self { $($c: self.$c - rhs.$c),+ }
+            }
+        }
+
+        impl<I> SubAssign for $name<I>
+        where
+            I: PrimInt,
+        {
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl<I> Mul<I> for $name<I>
+        where
+            I: PrimInt,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: I) -> Self::Output {
+                Self { $($c: self.$c * rhs),+ }
+            }
+        }
+
+        impl<I> MulAssign<I> for $name<I>
+        where
+            I: PrimInt,
+        {
+            #[inline]
+            fn mul_assign(&mut self, rhs: I) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl<I> Div<I> for $name<I>
+        where
+            I: PrimInt,
+        {
+            type Output = Self;
+
+            /// Divides all components by `rhs`, truncating toward zero.
+            #[inline]
+            fn div(self, rhs: I) -> Self::Output {
+                Self { $($c: self.$c / rhs),+ }
+            }
+        }
+
+        impl<I> DivAssign<I> for $name<I>
+        where
+            I: PrimInt,
+        {
+            #[inline]
+            fn div_assign(&mut self, rhs: I) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl<I> Neg for $name<I>
+        where
+            I: PrimInt + Signed,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self::Output {
+                Self { $($c: -self.$c),+ }
+            }
+        }
+
+        impl<I> Index<usize> for $name<I>
+        where
+            I: PrimInt,
+        {
+            type Output = I;
+
+            /// Gets the component by index.
+            /// # Panics
+            /// If index is out of range.
+            #[inline]
+            fn index(&self, index: usize) -> &Self::Output {
+                match index {
+                    $($i => &self.$c,)+
+                    _ => panic!("Index out of range"),
+                }
+            }
+        }
+
+        impl<I> IndexMut<usize> for $name<I>
+        where
+            I: PrimInt,
+        {
+            /// Gets the mutable component by index.
+            /// # Panics
+            /// If index is out of range.
+            #[inline]
+            fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+                match index {
+                    $($i => &mut self.$c,)+
+                    _ => panic!("Index out of range"),
+                }
+            }
+        }
+
+        impl<I> Display for $name<I>
+        where
+            I: PrimInt + Display,
+        {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                super::fmt_tuple(f, &[$(self.$c),+])
+            }
+        }
+
+        impl<I> FromStr for $name<I>
+        where
+            I: PrimInt + FromStr,
+        {
+            type Err = ParseVecError;
+
+            #[inline]
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let [$($c),+] = super::parse_tuple(s)?;
+                Ok(Self::new($($c),+))
+            }
+        }
+
+        impl From<$name<i32>> for $float<f64> {
+            /// Converts the vector losslessly.
+            #[inline]
+            fn from(v: $name<i32>) -> Self {
+                Self::new($(v.$c.into()),+)
+            }
+        }
+    };
+}
+
+int_vec!(
+    /// ```
+    /// # use ewq::vec::{Vec2i, Vec2d, Vec2f};
+    /// let v = Vec2i::new(3, -4);
+    /// assert_eq!(Vec2d::from(v), Vec2d::new(3., -4.));
+    /// assert_eq!(v.to_float_lossy::<f32>(), Vec2f::new(3., -4.));
+    /// assert_eq!(Vec2i::new(16_777_217, 0).to_float_lossy::<f32>().x, 16_777_216.);
+    /// assert_eq!(Vec2i::from_float(Vec2f::new(3.7, -4.2)), Some(v));
+    /// assert_eq!(Vec2i::from_float(Vec2f::new(f32::NAN, 0.)), None);
+    /// assert_eq!((v + v) * 2 / 4, v);
+    /// ```
+    IVec2, Vec2, 2, x: 0, y: 1
+);
+int_vec!(
+    /// ```
+    /// # use ewq::vec::Vec3u;
+    /// let size = Vec3u::new(4, 3, 2);
+    /// let v = Vec3u::new(1, 2, 1);
+    /// assert_eq!(v.to_linear_index(size), Some(21));
+    /// assert_eq!(Vec3u::from_linear_index(21, size), Some(v));
+    /// assert_eq!(Vec3u::from_linear_index(24, size), None);
+    /// assert_eq!(v.saturating_sub(size), Vec3u::new(0, 0, 0));
+    /// assert_eq!(v.wrapping_sub(Vec3u::new(2, 0, 0)).x, u32::MAX);
+    /// ```
+    IVec3, Vec3, 3, x: 0, y: 1, z: 2
+);
+int_vec!(IVec4, Vec4, 4, x: 0, y: 1, z: 2, w: 3);
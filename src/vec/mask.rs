@@ -0,0 +1,134 @@
+use std::ops::{BitAnd, BitOr, Not};
+use crate::Scalar;
+use super::{Vec2, Vec3, Vec4};
+
+/// Generates boolean mask type along with the comparisons of the float vector producing it.
+macro_rules! mask_vec {
+    ($name:ident, $float:ident, $dim:literal, $a:literal, $b:literal, $($c:ident),+) => {
+        #[doc = concat!($dim, "D vector of booleans, produced by component-wise comparisons.")]
+        #[derive(Debug, PartialEq, Eq, Hash, Default, Clone, Copy)]
+        pub struct $name {
+            $(pub $c: bool,)+
+        }
+
+        impl $name {
+            /// Creates new mask.
+            #[inline(always)]
+            pub const fn new($($c: bool),+) -> Self {
+                Self { $($c),+ }
+            }
+
+            /// Checks if any component is set.
+            /// ```
+            #[doc = concat!("# use ewq::vec::", stringify!($float), ";")]
+            #[doc = concat!("let (a, b) = (", stringify!($float), "::new(", $a, "), ", stringify!($float), "::new(", $b, "));")]
+            /// assert!(a.cmplt(b).any() && !a.cmplt(b).all());
+            /// assert!(a.cmple(a).all() && !a.cmpne(a).any());
+            /// ```
+            #[inline]
+            pub fn any(&self) -> bool {
+                false $(|| self.$c)+
+            }
+
+            /// Checks if all components are set.
+            #[inline]
+            pub fn all(&self) -> bool {
+                true $(&& self.$c)+
+            }
+        }
+
+        impl BitAnd for $name {
+            type Output = Self;
+
+            #[inline]
+            fn bitand(self, rhs: Self) -> Self::Output {
+                Self { $($c: self.$c & rhs.$c),+ }
+            }
+        }
+
+        impl BitOr for $name {
+            type Output = Self;
+
+            #[inline]
+            fn bitor(self, rhs: Self) -> Self::Output {
+                Self { $($c: self.$c | rhs.$c),+ }
+            }
+        }
+
+        impl Not for $name {
+            type Output = Self;
+
+            #[inline]
+            fn not(self) -> Self::Output {
+                Self { $($c: !self.$c),+ }
+            }
+        }
+
+        impl<F> $float<F>
+        where
+            F: Scalar,
+        {
+            /// Compares components for `==`.
+            #[inline]
+            pub fn cmpeq(&self, other: Self) -> $name {
+                $name { $($c: self.$c == other.$c),+ }
+            }
+
+            /// Compares components for `!=`.
+            #[inline]
+            pub fn cmpne(&self, other: Self) -> $name {
+                $name { $($c: self.$c != other.$c),+ }
+            }
+
+            /// Compares components for `<`.
+            #[inline]
+            pub fn cmplt(&self, other: Self) -> $name {
+                $name { $($c: self.$c < other.$c),+ }
+            }
+
+            /// Compares components for `<=`.
+            #[inline]
+            pub fn cmple(&self, other: Self) -> $name {
+                $name { $($c: self.$c <= other.$c),+ }
+            }
+
+            /// Compares components for `>`.
+            #[inline]
+            pub fn cmpgt(&self, other: Self) -> $name {
+                $name { $($c: self.$c > other.$c),+ }
+            }
+
+            /// Compares components for `>=`.
+            #[inline]
+            pub fn cmpge(&self, other: Self) -> $name {
+                $name { $($c: self.$c >= other.$c),+ }
+            }
+
+            /// Creates new vector taking components from `a` where `mask` is set and from `b` otherwise.
+            /// ```
+            #[doc = concat!("# use ewq::vec::", stringify!($float), ";")]
+            #[doc = concat!("let (a, b) = (", stringify!($float), "::new(", $a, "), ", stringify!($float), "::new(", $b, "));")]
+            #[doc = concat!("assert_eq!(", stringify!($float), "::select(a.cmplt(b), a, b), a.min(b));")]
+            #[doc = concat!("assert_eq!(", stringify!($float), "::select(a.cmpge(b), a, b), a.max(b));")]
+            /// ```
+            #[inline]
+            pub fn select(mask: $name, a: Self, b: Self) -> Self {
+                Self::new($(if mask.$c { a.$c } else { b.$c }),+)
+            }
+        }
+    };
+}
+
+mask_vec!(BVec2, Vec2, 2, "1., 2.", "3., 2.", x, y);
+mask_vec!(BVec3, Vec3, 3, "1., 2., 3.", "3., 2., 1.", x, y, z);
+mask_vec!(
+    BVec4,
+    Vec4,
+    4,
+    "1., 2., 3., 4.",
+    "4., 3., 2., 1.",
+    x,
+    y,
+    z,
+    w
+);
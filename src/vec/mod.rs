@@ -1,3 +1,27 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, Div, Mul};
+use std::str::FromStr;
+use crate::Real;
+
+/// Generates swizzle accessors, i.e. `v.zyx()`, for the vector type.
+macro_rules! swizzle {
+    ($vec:ident { $($name:ident => $out:ident($($c:ident),+)),+ $(,)? }) => {
+        impl<F> $vec<F>
+        where
+            F: $crate::Scalar,
+        {
+            $(
+                #[doc = concat!("Returns new vector made of `", stringify!($($c),+), "` components.")]
+                #[inline]
+                pub fn $name(&self) -> $crate::vec::$out<F> {
+                    $crate::vec::$out::new($(self.$c),+)
+                }
+            )+
+        }
+    };
+}
+
 mod d2;
 pub use d2::*;
 
@@ -6,3 +30,122 @@ pub use d3::*;
 
 mod d4;
 pub use d4::*;
+
+mod dn;
+pub use dn::*;
+
+mod hash;
+pub use hash::*;
+
+mod int;
+pub use int::*;
+
+mod mask;
+pub use mask::*;
+
+/// Structure-of-arrays storage and batch kernels.
+pub mod soa;
+
+#[cfg(feature = "half")]
+mod half;
+#[cfg(feature = "half")]
+pub use self::half::*;
+
+/// Error returned when parsing a vector from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseVecError {
+    /// The string has a different number of components than the vector.
+    ComponentCount { expected: usize, found: usize },
+    /// The component at the given index is not a valid number.
+    InvalidComponent(usize),
+}
+
+impl Display for ParseVecError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ComponentCount { expected, found } => {
+                write!(f, "expected {} components, found {}", expected, found)
+            }
+            Self::InvalidComponent(i) => write!(f, "invalid component at index {}", i),
+        }
+    }
+}
+
+impl Error for ParseVecError {}
+
+/// Writes components as a tuple, i.e. `(1, 2, 3)`.
+/// Respects the precision of the formatter.
+fn fmt_tuple<F>(f: &mut Formatter<'_>, components: &[F]) -> fmt::Result
+where
+    F: Display,
+{
+    write!(f, "(")?;
+    for (i, c) in components.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        match f.precision() {
+            Some(p) => write!(f, "{:.*}", p, c)?,
+            None => write!(f, "{}", c)?,
+        }
+    }
+    write!(f, ")")
+}
+
+/// Parses comma separated components, optionally enclosed in parentheses,
+/// i.e. `(1, 2, 3)` or `1,2,3`.
+fn parse_tuple<F, const N: usize>(s: &str) -> Result<[F; N], ParseVecError>
+where
+    F: FromStr,
+{
+    let s = s.trim();
+    let s = s
+        .strip_prefix('(')
+        .and_then(|s| s.strip_suffix(')'))
+        .unwrap_or(s);
+    let components = s
+        .split(',')
+        .enumerate()
+        .map(|(i, c)| {
+            c.trim()
+                .parse()
+                .map_err(|_| ParseVecError::InvalidComponent(i))
+        })
+        .collect::<Result<Vec<F>, _>>()?;
+    let found = components.len();
+    components
+        .try_into()
+        .map_err(|_| ParseVecError::ComponentCount { expected: N, found })
+}
+
+/// Computes the arithmetic mean of the vectors or `None` if the iterator is empty.
+/// Vectors are counted as `usize`, so the count stays exact past the float's integer range.
+fn mean<V, F, I>(vectors: I) -> Option<V>
+where
+    V: Add<Output = V> + Div<F, Output = V>,
+    F: Real,
+    I: IntoIterator<Item = V>,
+{
+    let mut vectors = vectors.into_iter();
+    let first = vectors.next()?;
+    let (sum, count) = vectors.fold((first, 1usize), |(sum, count), v| (sum + v, count + 1));
+    Some(sum / F::from(count).unwrap())
+}
+
+/// Computes the mean of the vectors weighted by the paired weights
+/// or `None` if the weights sum up to zero.
+fn weighted_mean<V, F, I>(vectors: I) -> Option<V>
+where
+    V: Add<Output = V> + Mul<F, Output = V> + Div<F, Output = V>,
+    F: Real,
+    I: IntoIterator<Item = (V, F)>,
+{
+    let mut vectors = vectors.into_iter();
+    let (v, w) = vectors.next()?;
+    let (sum, total) = vectors.fold((v * w, w), |(sum, total), (v, w)| (sum + v * w, total + w));
+    if total != F::zero() {
+        Some(sum / total)
+    } else {
+        None
+    }
+}
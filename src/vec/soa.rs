@@ -0,0 +1,221 @@
+use std::iter::FromIterator;
+use crate::mat::Mat3;
+use crate::{scalar::mul_add, Real, Scalar};
+use super::Vec3;
+
+/// Buffer of 3D vectors stored as three separate component arrays,
+/// so loops over large numbers of vectors can be vectorized by the compiler.
+/// ```
+/// # use ewq::{mat::Mat3f, vec::{soa::Vec3xN, Vec3f}};
+/// let mut v: Vec3xN<f32> = [Vec3f::new(3., 0., 4.), Vec3f::new(0., 2., 0.)].into_iter().collect();
+/// v.normalize_all();
+/// v.transform_all(&Mat3f::identity());
+/// let mut dots = [0.; 2];
+/// v.dot_each(Vec3f::new(0., 1., 0.), &mut dots);
+/// assert_eq!(dots, [0., 1.]);
+/// v.dot_pairwise(&v.clone(), &mut dots);
+/// assert_eq!(dots, [1., 1.]);
+/// assert_eq!(v.get(0), Some(Vec3f::new(0.6, 0., 0.8)));
+/// assert_eq!(v.x(), &[0.6, 0.]);
+/// ```
+#[derive(Debug, PartialEq, Default, Clone)]
+pub struct Vec3xN<F>
+where
+    F: Scalar,
+{
+    x: Vec<F>,
+    y: Vec<F>,
+    z: Vec<F>,
+}
+
+impl<F> Vec3xN<F>
+where
+    F: Scalar,
+{
+    /// Creates new empty buffer.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            x: Vec::new(),
+            y: Vec::new(),
+            z: Vec::new(),
+        }
+    }
+
+    /// Creates new empty buffer with space for at least `capacity` vectors.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            x: Vec::with_capacity(capacity),
+            y: Vec::with_capacity(capacity),
+            z: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the number of vectors in the buffer.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.x.len()
+    }
+
+    /// Checks if the buffer contains no vectors.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.x.is_empty()
+    }
+
+    /// Appends the vector to the end of the buffer.
+    #[inline]
+    pub fn push(&mut self, v: Vec3<F>) {
+        self.x.push(v.x);
+        self.y.push(v.y);
+        self.z.push(v.z);
+    }
+
+    /// Returns the vector at `index` or `None` if it is out of range.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<Vec3<F>> {
+        Some(Vec3::new(
+            *self.x.get(index)?,
+            *self.y.get(index)?,
+            *self.z.get(index)?,
+        ))
+    }
+
+    /// Replaces the vector at `index`.
+    /// # Panics
+    /// If `index >= len`.
+    #[inline]
+    pub fn set(&mut self, index: usize, v: Vec3<F>) {
+        self.x[index] = v.x;
+        self.y[index] = v.y;
+        self.z[index] = v.z;
+    }
+
+    /// Returns the X components of all vectors.
+    #[inline]
+    pub fn x(&self) -> &[F] {
+        &self.x
+    }
+
+    /// Returns the Y components of all vectors.
+    #[inline]
+    pub fn y(&self) -> &[F] {
+        &self.y
+    }
+
+    /// Returns the Z components of all vectors.
+    #[inline]
+    pub fn z(&self) -> &[F] {
+        &self.z
+    }
+
+    /// Returns the X, Y and Z components of all vectors for modification.
+    #[inline]
+    pub fn as_mut_slices(&mut self) -> (&mut [F], &mut [F], &mut [F]) {
+        (&mut self.x, &mut self.y, &mut self.z)
+    }
+
+    /// Returns an iterator over the vectors.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = Vec3<F>> + '_ {
+        self.x
+            .iter()
+            .zip(&self.y)
+            .zip(&self.z)
+            .map(|((x, y), z)| Vec3::new(*x, *y, *z))
+    }
+
+    /// Computes dot product of every vector with `v`, writing the results to `out`.
+    /// # Panics
+    /// If `out.len() != len`.
+    #[inline]
+    pub fn dot_each(&self, v: Vec3<F>, out: &mut [F]) {
+        assert_eq!(out.len(), self.len(), "Length mismatch");
+        for (((o, x), y), z) in out.iter_mut().zip(&self.x).zip(&self.y).zip(&self.z) {
+            *o = *x * v.x + *y * v.y + *z * v.z;
+        }
+    }
+
+    /// Computes dot products between the vectors at the same indices of two buffers,
+    /// writing the results to `out`.
+    /// # Panics
+    /// If lengths of the buffers or `out` differ.
+    #[inline]
+    pub fn dot_pairwise(&self, other: &Self, out: &mut [F]) {
+        assert!(
+            out.len() == self.len() && other.len() == self.len(),
+            "Length mismatch"
+        );
+        let a = self.x.iter().zip(&self.y).zip(&self.z);
+        let b = other.x.iter().zip(&other.y).zip(&other.z);
+        for (o, (((ax, ay), az), ((bx, by), bz))) in out.iter_mut().zip(a.zip(b)) {
+            *o = *ax * *bx + *ay * *by + *az * *bz;
+        }
+    }
+
+    /// Transforms every vector by the matrix in place.
+    #[inline]
+    pub fn transform_all(&mut self, m: &Mat3<F>) {
+        let (r0, r1, r2) = (m.row::<0>(), m.row::<1>(), m.row::<2>());
+        for ((x, y), z) in self.x.iter_mut().zip(&mut self.y).zip(&mut self.z) {
+            let v = Vec3::new(*x, *y, *z);
+            *x = v.dot(r0);
+            *y = v.dot(r1);
+            *z = v.dot(r2);
+        }
+    }
+}
+
+impl<F> Vec3xN<F>
+where
+    F: Real,
+{
+    /// Normalizes every vector in place.
+    /// Multiplies by the reciprocal magnitude, so results may differ from [`Vec3::normalized`]
+    /// in the last bit. Vectors with zero magnitude become NaN.
+    #[inline]
+    pub fn normalize_all(&mut self) {
+        for ((x, y), z) in self.x.iter_mut().zip(&mut self.y).zip(&mut self.z) {
+            let r = mul_add(*z, *z, mul_add(*y, *y, *x * *x)).sqrt().recip();
+            *x = *x * r;
+            *y = *y * r;
+            *z = *z * r;
+        }
+    }
+}
+
+impl<F> FromIterator<Vec3<F>> for Vec3xN<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Vec3<F>>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let mut out = Self::with_capacity(iter.size_hint().0);
+        for v in iter {
+            out.push(v);
+        }
+        out
+    }
+}
+
+impl<F> From<&[Vec3<F>]> for Vec3xN<F>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn from(vectors: &[Vec3<F>]) -> Self {
+        vectors.iter().copied().collect()
+    }
+}
+
+impl<F> From<Vec3xN<F>> for Vec<Vec3<F>>
+where
+    F: Scalar,
+{
+    #[inline]
+    fn from(v: Vec3xN<F>) -> Self {
+        v.iter().collect()
+    }
+}